@@ -0,0 +1,63 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// A puzzle answer. Most days answer with a number, but some (like day 21's canonical list of
+/// dangerous ingredients) answer with text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+macro_rules! impl_from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(answer: $int) -> Self {
+                    Answer::Integer(answer as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::Text(answer.to_owned())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(answer) => write!(f, "{}", answer),
+            Answer::Text(answer) => write!(f, "{}", answer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_answers() {
+        assert_eq!(Answer::from(514579u32), Answer::Integer(514579));
+        assert_eq!(Answer::from(-3i64), Answer::Integer(-3));
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(
+            Answer::from("mxmxvkd,sqjhc,fvjkl"),
+            Answer::Text("mxmxvkd,sqjhc,fvjkl".to_string())
+        );
+    }
+}
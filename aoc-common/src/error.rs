@@ -0,0 +1,112 @@
+use anyhow::Error;
+use thiserror::Error;
+
+/// Why the puzzle input couldn't be parsed, pointing at the offending text. Line and column
+/// numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("line {line}, column {column}: {reason}: {text:?}")]
+    Invalid {
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    #[error("line {line}: unexpected end of input, expected {expected}")]
+    UnexpectedEnd { line: usize, expected: String },
+}
+
+impl ParseError {
+    pub fn invalid(line: usize, column: usize, text: &str, reason: impl Into<String>) -> Self {
+        ParseError::Invalid {
+            line,
+            column,
+            text: text.to_owned(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn unexpected_end(line: usize, expected: impl Into<String>) -> Self {
+        ParseError::UnexpectedEnd {
+            line,
+            expected: expected.into(),
+        }
+    }
+
+    /// Locates an error from parsing a whole line. A [`TokenError`] keeps its column and text,
+    /// while any other error points at the whole line.
+    pub fn from_line(line: usize, text: &str, error: Error) -> Self {
+        match error.downcast::<ParseError>() {
+            Ok(error) => error,
+            Err(error) => match error.downcast::<TokenError>() {
+                Ok(token) => ParseError::Invalid {
+                    line,
+                    column: token.column,
+                    text: token.text,
+                    reason: token.reason,
+                },
+                Err(error) => ParseError::invalid(line, 1, text, format!("{:#}", error)),
+            },
+        }
+    }
+}
+
+/// A token within a line that couldn't be parsed. Parsers for a single line don't know which line
+/// they are on, so they return this and [`parse_lines`](crate::parse_lines) adds the line number.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("column {column}: {reason}: {text:?}")]
+pub struct TokenError {
+    pub column: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl TokenError {
+    /// An error for `token`, which must be a slice of `line`.
+    pub fn new(line: &str, token: &str, reason: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset <= line.len())
+            .unwrap_or(0);
+        Self {
+            column: line[..offset].chars().count() + 1,
+            text: token.to_owned(),
+            reason: reason.into(),
+        }
+    }
+
+    /// An error for the character starting at byte `index` of `line`.
+    pub fn char_at(line: &str, index: usize, reason: impl Into<String>) -> Self {
+        let len = line[index..].chars().next().map_or(0, char::len_utf8);
+        Self::new(line, &line[index..index + len], reason)
+    }
+
+    /// An error for something missing from the end of `line`.
+    pub fn missing(line: &str, reason: impl Into<String>) -> Self {
+        Self::new(line, &line[line.len()..], reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_tokens() {
+        let line = "mask = 1X0";
+        assert_eq!(
+            TokenError::new(line, &line[7..], "expected a mask"),
+            TokenError {
+                column: 8,
+                text: "1X0".to_string(),
+                reason: "expected a mask".to_string(),
+            }
+        );
+        assert_eq!(TokenError::char_at(line, 8, "bad bit").text, "X");
+        assert_eq!(TokenError::missing(line, "expected more").column, 11);
+        assert_eq!(
+            ParseError::invalid(2, 3, "x4", "expected a number").to_string(),
+            "line 2, column 3: expected a number: \"x4\""
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::error::ParseError;
+use crate::parse::parse_grid;
+
+/// The offsets to the 8 neighbors of a cell, including diagonals, in reading order.
+pub const ADJACENT_VECTORS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// The offsets to the 4 neighbors of a cell that share an edge with it: up, left, right and down.
+pub const ORTHOGONAL_VECTORS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// A dense rectangular grid of cells stored row by row, indexed by `(x, y)` from the top left. It
+/// always has at least one cell.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid2D<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid2D<T> {
+    /// Builds a grid from rows of cells, or `None` if the rows are empty or not all the same width.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        let height = rows.len();
+        Some(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Builds a grid by calling `cell` with the position of every cell. Panics if `width` or
+    /// `height` is 0, since a grid always has at least one cell.
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Self
+    where
+        F: FnMut((usize, usize)) -> T,
+    {
+        assert!(
+            width > 0 && height > 0,
+            "A grid can't be {}x{}, it needs at least one cell",
+            width,
+            height
+        );
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Parses the grid with [`parse_grid`], one cell for every character.
+    pub fn parse<F>(input: &str, expected: &str, parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let rows = parse_grid(input, expected, parse_cell)?;
+        Ok(Self::from_rows(rows).expect("parse_grid returns rectangular grids"))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Like [`Grid2D::get`], but for positions that may have gone off the top or left.
+    pub fn get_signed(&self, x: isize, y: isize) -> Option<&T> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.get(x as usize, y as usize)
+    }
+
+    /// The cell at `(x, y)` with the grid repeating forever in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /// The position one step of `(dx, dy)` away from `(x, y)`, if it is still inside the grid.
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// The positions and cells at each offset in `vectors` from `(x, y)` that are inside the grid.
+    pub fn neighbors_at<'a>(
+        &'a self,
+        position: (usize, usize),
+        vectors: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        vectors.iter().filter_map(move |&vector| {
+            let (x, y) = self.offset(position, vector)?;
+            Some(((x, y), &self[(x, y)]))
+        })
+    }
+
+    /// The up to 8 cells around `(x, y)`, including diagonals.
+    pub fn neighbors(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbors_at(position, &ADJACENT_VECTORS)
+    }
+
+    /// The up to 4 cells sharing an edge with `(x, y)`.
+    pub fn orthogonal_neighbors(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.neighbors_at(position, &ORTHOGONAL_VECTORS)
+    }
+
+    /// The cells in row `y` from left to right, or `None` if `y` is outside the grid.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y < self.height {
+            Some(&self.cells[y * self.width..(y + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells in column `x` from top to bottom, or `None` if `x` is outside the grid.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        if x < self.width {
+            Some(self.cells[x..].iter().step_by(self.width))
+        } else {
+            None
+        }
+    }
+
+    /// Every cell in reading order, with its position.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(index, cell)| ((index % width, index / width), cell))
+    }
+
+    /// A grid of the same size with `f` applied to every cell and its position.
+    pub fn map<U, F>(&self, mut f: F) -> Grid2D<U>
+    where
+        F: FnMut((usize, usize), &T) -> U,
+    {
+        Grid2D {
+            cells: self
+                .iter()
+                .map(|(position, cell)| f(position, cell))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid2D<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).unwrap_or_else(|| {
+            panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                x, y, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid2D<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({}, {}) is outside of the {}x{} grid", x, y, width, height))
+    }
+}
+
+/// Prints each cell with its own `Display`, one row per line, so a grid parsed from cells that
+/// print as the character they were parsed from prints as its input.
+impl<T: fmt::Display> fmt::Display for Grid2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_digit(c: char) -> Option<u32> {
+        c.to_digit(10)
+    }
+
+    #[test]
+    fn parses_and_prints_grids() {
+        let input = "123\n456\n";
+        let grid = Grid2D::parse(input, "a digit", parse_digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), input);
+        assert_eq!(
+            Grid2D::parse("12\n4x\n", "a digit", parse_digit),
+            Err(ParseError::invalid(2, 2, "x", "expected a digit"))
+        );
+        assert_eq!(Grid2D::from_rows(vec![vec![1], vec![2, 3]]), None);
+        assert_eq!(Grid2D::<u32>::from_rows(vec![]), None);
+        assert_eq!(Grid2D::from_fn(3, 2, |(x, y)| (y * 3 + x + 1) as u32), grid);
+        assert_eq!(Grid2D::from_rows(vec![Vec::<u32>::new()]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "A grid can't be 0x2, it needs at least one cell")]
+    fn rejects_empty_grids() {
+        Grid2D::from_fn(0, 2, |_| 0);
+    }
+
+    #[test]
+    fn indexes_cells() {
+        let mut grid = Grid2D::parse("123\n456\n", "a digit", parse_digit).unwrap();
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 1), Some(&5));
+        assert_eq!(grid.get_wrapping(4, 0), &2);
+        assert_eq!(grid.get_wrapping(-1, -1), &6);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (2, 1)), Some((2, 1)));
+        assert_eq!(grid.offset((2, 1), (1, 0)), None);
+        grid[(0, 0)] = 9;
+        assert_eq!(grid.row(0), Some(&[9, 2, 3][..]));
+        assert_eq!(grid.row(2), None);
+    }
+
+    #[test]
+    fn iterates_cells() {
+        let grid = Grid2D::parse("123\n456\n789\n", "a digit", parse_digit).unwrap();
+        let neighbors: Vec<u32> = grid.neighbors((0, 0)).map(|(_, &cell)| cell).collect();
+        assert_eq!(neighbors, vec![2, 4, 5]);
+        assert_eq!(grid.neighbors((1, 1)).count(), 8);
+        let orthogonal: Vec<(usize, usize)> = grid
+            .orthogonal_neighbors((1, 1))
+            .map(|(position, _)| position)
+            .collect();
+        assert_eq!(orthogonal, vec![(1, 0), (0, 1), (2, 1), (1, 2)]);
+        assert_eq!(
+            grid.column(1).unwrap().copied().collect::<Vec<u32>>(),
+            vec![2, 5, 8]
+        );
+        assert!(grid.column(3).is_none());
+        assert_eq!(grid.rows().nth(2), Some(&[7, 8, 9][..]));
+        assert_eq!(grid.iter().nth(5), Some(((2, 1), &6)));
+        assert_eq!(
+            grid.map(|(x, y), &cell| cell + (x * y) as u32).to_string(),
+            "123\n468\n71013\n"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::Parser;
+
+/// Opens the input file at `path` for buffered reading.
+pub fn open_input<P: AsRef<Path>>(path: P) -> Result<BufReader<File>> {
+    let path = path.as_ref();
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open input file: {}", path.display()))?;
+    Ok(BufReader::new(file))
+}
+
+/// Reads the whole input file at `path` into a string.
+pub fn read_input<P: AsRef<Path>>(path: P) -> Result<String> {
+    let path = path.as_ref();
+    fs::read_to_string(path)
+        .with_context(|| format!("Failed to read input file: {}", path.display()))
+}
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// Reads the whole input into a string. Stdin can only be read once, so the solvers are given
+    /// this string rather than the source itself.
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::Path(path) => read_input(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .context("Failed to read input from stdin")?;
+                Ok(input)
+            }
+            InputSource::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+/// Command-line arguments that select a day's puzzle input.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct InputArgs {
+    /// Path to the puzzle input, or `-` to read it from stdin.
+    #[arg(value_name = "INPUT")]
+    pub input: Option<PathBuf>,
+
+    /// Use this string as the puzzle input instead of reading a file.
+    #[arg(long, value_name = "STRING", conflicts_with = "input")]
+    pub input_str: Option<String>,
+}
+
+impl InputArgs {
+    /// Returns true when no input was selected, so the default should be used.
+    pub fn is_empty(&self) -> bool {
+        self.input.is_none() && self.input_str.is_none()
+    }
+
+    /// The selected input, falling back to the file at `default` when none was given.
+    pub fn source<P: Into<PathBuf>>(&self, default: P) -> InputSource {
+        match (&self.input, &self.input_str) {
+            (_, Some(input)) => InputSource::Inline(input.clone()),
+            (Some(path), None) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), None) => InputSource::Path(path.clone()),
+            (None, None) => InputSource::Path(default.into()),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct DayArgs {
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+/// Reads the puzzle input selected on a day's command line, defaulting to the file at `default`.
+pub fn input_from_args(default: &str) -> Result<String> {
+    DayArgs::parse().input.source(default).read()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> InputArgs {
+        DayArgs::try_parse_from(std::iter::once("day01").chain(args.iter().copied()))
+            .unwrap()
+            .input
+    }
+
+    #[test]
+    fn reports_missing_input_path() {
+        let error = read_input("input/missing.txt").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Failed to read input file: input/missing.txt"
+        );
+    }
+
+    #[test]
+    fn selects_input_source() {
+        assert_eq!(
+            parse(&[]).source("input/input.txt"),
+            InputSource::Path("input/input.txt".into())
+        );
+        assert_eq!(
+            parse(&["input/test.txt"]).source("input/input.txt"),
+            InputSource::Path("input/test.txt".into())
+        );
+        assert_eq!(parse(&["-"]).source("input/input.txt"), InputSource::Stdin);
+        assert_eq!(
+            parse(&["--input-str", "1721\n979"]).source("input/input.txt"),
+            InputSource::Inline("1721\n979".into())
+        );
+    }
+
+    #[test]
+    fn rejects_path_and_inline_input_together() {
+        assert!(
+            DayArgs::try_parse_from(["day01", "input/test.txt", "--input-str", "1721"]).is_err()
+        );
+    }
+}
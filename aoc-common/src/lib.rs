@@ -0,0 +1,25 @@
+//! Code shared by every day's solution: loading puzzle input, the [`Solution`] trait each day
+//! implements, reporting where malformed input failed to parse, and timing the solvers and printing
+//! their answers in a common format.
+
+pub mod answer;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod modular;
+pub mod output;
+pub mod parse;
+pub mod records;
+pub mod report;
+pub mod solution;
+pub mod timing;
+
+pub use answer::Answer;
+pub use error::{ParseError, TokenError};
+pub use grid::Grid2D;
+pub use input::{input_from_args, open_input, read_input, InputArgs, InputSource};
+pub use parse::{parse_grid, parse_lines};
+pub use records::{numbered_lines, Line, Record, Records};
+pub use report::{catch_panic, print_report, run, solve, PartReport, Report};
+pub use solution::{NoParams, Solution};
+pub use timing::time;
@@ -0,0 +1,64 @@
+//! Modular arithmetic for the puzzles that are number theory in disguise.
+
+// egcd and mod_inv are from: https://rosettacode.org/wiki/Chinese_remainder_theorem#Rust
+
+/// The extended Euclidean algorithm, returning `(gcd, x, y)` such that `a * x + b * y = gcd`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if a == 0 {
+        (b, 0, 1)
+    } else {
+        let (g, x, y) = egcd(b % a, a);
+        (g, y - (b / a) * x, x)
+    }
+}
+
+/// The multiplicative inverse of `x` modulo `n`, if `x` and `n` are coprime.
+pub fn mod_inv(x: i64, n: i64) -> Option<i64> {
+    let (g, x, _) = egcd(x, n);
+    if g == 1 {
+        Some((x % n + n) % n)
+    } else {
+        None
+    }
+}
+
+/// `(a * b) % n`, without overflowing when the product doesn't fit in an `i64`.
+pub fn mod_mul(a: i64, b: i64, n: i64) -> i64 {
+    ((a as i128 * b as i128).rem_euclid(n as i128)) as i64
+}
+
+/// `base` raised to `exponent` modulo `n`, by repeated squaring.
+pub fn mod_pow(base: i64, mut exponent: u64, n: i64) -> i64 {
+    let mut result = 1 % n;
+    let mut base = base.rem_euclid(n);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, n);
+        }
+        base = mod_mul(base, base, n);
+        exponent >>= 1;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverts() {
+        assert_eq!(egcd(240, 46), (2, -9, 47));
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(42, 2017), Some(1969));
+        assert_eq!(mod_inv(4, 8), None);
+    }
+
+    #[test]
+    fn raises_to_powers() {
+        assert_eq!(mod_pow(7, 8, 20201227), 5764801);
+        assert_eq!(mod_pow(17807724, 8, 20201227), 14897079);
+        assert_eq!(mod_pow(2, 0, 1), 0);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_mul(i64::MAX, i64::MAX, 1_000_000_007), 737564071);
+    }
+}
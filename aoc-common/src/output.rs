@@ -0,0 +1,73 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use anyhow::{Error, Result};
+
+/// Formats a part's answer (or the error that stopped it) followed by the time it took.
+pub fn format_part<T: Display>(part: u8, answer: &Result<T>, elapsed: Duration) -> String {
+    match answer {
+        Ok(answer) => format!("Part {}: {}\n(elapsed: {:?})", part, answer, elapsed),
+        Err(error) => format!(
+            "Part {} failed: {:#}\n(elapsed: {:?})",
+            part, error, elapsed
+        ),
+    }
+}
+
+/// Formats the outcome of parsing the input followed by the time it took.
+pub fn format_parse(error: Option<&Error>, elapsed: Duration) -> String {
+    match error {
+        None => format!("Parsed input\n(elapsed: {:?})", elapsed),
+        Some(error) => format!(
+            "Failed to parse input: {:#}\n(elapsed: {:?})",
+            error, elapsed
+        ),
+    }
+}
+
+pub fn print_part<T: Display>(part: u8, answer: &Result<T>, elapsed: Duration) {
+    println!("{}", format_part(part, answer, elapsed));
+}
+
+pub fn print_parse(error: Option<&Error>, elapsed: Duration) {
+    println!("{}", format_parse(error, elapsed));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::anyhow;
+
+    #[test]
+    fn formats_answer() {
+        assert_eq!(
+            format_part(1, &Ok(514579), Duration::from_millis(2)),
+            "Part 1: 514579\n(elapsed: 2ms)"
+        );
+    }
+
+    #[test]
+    fn formats_error() {
+        let answer: Result<u32> = Err(anyhow!("No fix found"));
+        assert_eq!(
+            format_part(2, &answer, Duration::from_millis(2)),
+            "Part 2 failed: No fix found\n(elapsed: 2ms)"
+        );
+    }
+
+    #[test]
+    fn formats_parse() {
+        assert_eq!(
+            format_parse(None, Duration::from_millis(2)),
+            "Parsed input\n(elapsed: 2ms)"
+        );
+        assert_eq!(
+            format_parse(
+                Some(&anyhow!("Unexpected end of input")),
+                Duration::from_millis(2)
+            ),
+            "Failed to parse input: Unexpected end of input\n(elapsed: 2ms)"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use crate::error::{ParseError, TokenError};
+
+/// Parses every line of the input with `parse_line`, reporting where the first bad line is.
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&str) -> anyhow::Result<T>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            parse_line(line).map_err(|error| ParseError::from_line(index + 1, line, error))
+        })
+        .collect()
+}
+
+/// Parses a rectangular grid of characters into rows of cells, reporting where any character
+/// `parse_cell` doesn't recognize is, or which row is a different width to the first.
+pub fn parse_grid<T, F>(
+    input: &str,
+    expected: &str,
+    parse_cell: F,
+) -> Result<Vec<Vec<T>>, ParseError>
+where
+    F: Fn(char) -> Option<T>,
+{
+    let rows = parse_lines(input, |line| {
+        line.char_indices()
+            .map(|(index, c)| {
+                parse_cell(c).ok_or_else(|| {
+                    TokenError::char_at(line, index, format!("expected {}", expected)).into()
+                })
+            })
+            .collect::<anyhow::Result<Vec<T>>>()
+    })?;
+    let width = rows.first().map_or(0, Vec::len);
+    if width == 0 {
+        return Err(ParseError::unexpected_end(1, "a row of the grid"));
+    }
+    match rows.iter().position(|row| row.len() != width) {
+        Some(index) => Err(ParseError::invalid(
+            index + 1,
+            1,
+            input.lines().nth(index).unwrap_or_default(),
+            format!("expected {} cells like the first row", width),
+        )),
+        None => Ok(rows),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::anyhow;
+
+    fn parse_pair(line: &str) -> anyhow::Result<(u32, u32)> {
+        let mut nums = line.split(',');
+        let left = nums.next().unwrap_or_default();
+        let right = nums
+            .next()
+            .ok_or_else(|| TokenError::missing(line, "expected a comma"))?;
+        let parse = |num: &str| {
+            num.parse()
+                .map_err(|_| TokenError::new(line, num, "expected a number"))
+        };
+        Ok((parse(left)?, parse(right)?))
+    }
+
+    #[test]
+    fn parses_lines() {
+        assert_eq!(
+            parse_lines("1,2\n3,4\n", parse_pair),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+    }
+
+    #[test]
+    fn locates_bad_tokens() {
+        assert_eq!(
+            parse_lines("1,2\n3,x4\n", parse_pair),
+            Err(ParseError::invalid(2, 3, "x4", "expected a number"))
+        );
+        assert_eq!(
+            parse_lines("1,2\n3,4\n56", parse_pair),
+            Err(ParseError::invalid(3, 3, "", "expected a comma"))
+        );
+    }
+
+    #[test]
+    fn parses_grids() {
+        let parse_cell = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+        assert_eq!(
+            parse_grid("#.\n.#\n", "'#' or '.'", parse_cell),
+            Ok(vec![vec![true, false], vec![false, true]])
+        );
+        assert_eq!(
+            parse_grid("#.\n.x\n", "'#' or '.'", parse_cell),
+            Err(ParseError::invalid(2, 2, "x", "expected '#' or '.'"))
+        );
+        assert_eq!(
+            parse_grid("#.\n.#.\n", "'#' or '.'", parse_cell),
+            Err(ParseError::invalid(
+                2,
+                1,
+                ".#.",
+                "expected 2 cells like the first row"
+            ))
+        );
+        assert_eq!(
+            parse_grid("", "'#' or '.'", parse_cell),
+            Err(ParseError::unexpected_end(1, "a row of the grid"))
+        );
+    }
+
+    #[test]
+    fn locates_other_errors() {
+        assert_eq!(
+            parse_lines("ok\nbad", |line| match line {
+                "ok" => Ok(()),
+                _ => Err(anyhow!("not ok")),
+            }),
+            Err(ParseError::invalid(2, 1, "bad", "not ok"))
+        );
+    }
+}
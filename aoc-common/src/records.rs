@@ -0,0 +1,226 @@
+use crate::error::ParseError;
+
+/// A line of the input without its line ending, numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Parses the line with `parse`, reporting errors at this line.
+    pub fn parse<T, F>(&self, parse: F) -> Result<T, ParseError>
+    where
+        F: FnOnce(&'a str) -> anyhow::Result<T>,
+    {
+        parse(self.text).map_err(|error| ParseError::from_line(self.number, self.text, error))
+    }
+}
+
+/// Numbers every line of the input. Lines can end in `\n` or `\r\n`.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    (1..)
+        .zip(input.lines())
+        .map(|(number, text)| Line { number, text })
+}
+
+/// A run of lines with a blank line, or the start or end of the input, on either side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Record<'a> {
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    /// The number of the record's first line.
+    pub fn first_line(&self) -> usize {
+        self.lines[0].number
+    }
+
+    /// The number of the record's last line.
+    pub fn last_line(&self) -> usize {
+        self.lines[self.lines.len() - 1].number
+    }
+
+    /// Checks that the record starts with a `header` line, like `your ticket:`, and returns the
+    /// lines after it.
+    pub fn section(&self, header: &str) -> Result<&[Line<'a>], ParseError> {
+        let first = &self.lines[0];
+        if first.text == header {
+            Ok(&self.lines[1..])
+        } else {
+            Err(ParseError::invalid(
+                first.number,
+                1,
+                first.text,
+                format!("expected {:?}", header),
+            ))
+        }
+    }
+
+    /// Parses every line of the record with `parse_line`, reporting where the first bad line is.
+    pub fn parse_lines<T, F>(&self, parse_line: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&'a str) -> anyhow::Result<T>,
+    {
+        parse_record_lines(&self.lines, parse_line)
+    }
+}
+
+/// Parses each of `lines` with `parse_line`, reporting where the first bad line is.
+pub fn parse_record_lines<'a, T, F>(
+    lines: &[Line<'a>],
+    mut parse_line: F,
+) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> anyhow::Result<T>,
+{
+    lines
+        .iter()
+        .map(|line| line.parse(&mut parse_line))
+        .collect()
+}
+
+/// Splits the input into records on blank lines. Lines that are only whitespace count as blank,
+/// and any number of them, including at the start or end of the input, separate records.
+pub struct Records<'a> {
+    records: std::vec::IntoIter<Record<'a>>,
+    end: usize,
+}
+
+impl<'a> Records<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let mut records = Vec::new();
+        let mut lines = Vec::new();
+        let mut end = 1;
+        for line in numbered_lines(input) {
+            end = line.number + 1;
+            if line.text.trim().is_empty() {
+                if !lines.is_empty() {
+                    records.push(Record {
+                        lines: std::mem::take(&mut lines),
+                    });
+                }
+            } else {
+                lines.push(line);
+            }
+        }
+        if !lines.is_empty() {
+            records.push(Record { lines });
+        }
+        Self {
+            records: records.into_iter(),
+            end,
+        }
+    }
+
+    /// The number of the line after the last line of the input, where errors about missing input
+    /// are reported.
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// The next record, or an error saying `expected` was missing if the input has run out.
+    pub fn expect(&mut self, expected: &str) -> Result<Record<'a>, ParseError> {
+        self.next()
+            .ok_or_else(|| ParseError::unexpected_end(self.end, expected))
+    }
+
+    /// Checks that there are no more records.
+    pub fn finish(mut self) -> Result<(), ParseError> {
+        match self.next() {
+            Some(record) => {
+                let line = &record.lines()[0];
+                Err(ParseError::invalid(
+                    line.number,
+                    1,
+                    line.text,
+                    "expected the end of the input",
+                ))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Record<'a>> {
+        self.records.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::anyhow;
+
+    fn texts<'a>(record: &Record<'a>) -> Vec<&'a str> {
+        record.lines().iter().map(|line| line.text).collect()
+    }
+
+    #[test]
+    fn splits_records() {
+        let records: Vec<Record> = Records::new("a\nb\n\nc\n").collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(texts(&records[0]), vec!["a", "b"]);
+        assert_eq!(texts(&records[1]), vec!["c"]);
+        assert_eq!((records[1].first_line(), records[1].last_line()), (4, 4));
+
+        let records: Vec<Record> = Records::new("\r\na\r\nb\r\n\r\n  \r\n\r\nc\r\n\r\n").collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(texts(&records[0]), vec!["a", "b"]);
+        assert_eq!(texts(&records[1]), vec!["c"]);
+        assert_eq!(records[1].first_line(), 7);
+
+        assert_eq!(Records::new("").count(), 0);
+        assert_eq!(Records::new("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn locates_errors() {
+        let mut records = Records::new("your ticket:\n7,1\n\n1\nx\n");
+        let ticket = records.expect("your ticket").unwrap();
+        assert_eq!(
+            ticket.section("your ticket:").unwrap(),
+            &[Line {
+                number: 2,
+                text: "7,1"
+            }]
+        );
+        assert_eq!(
+            ticket.section("nearby tickets:"),
+            Err(ParseError::invalid(
+                1,
+                1,
+                "your ticket:",
+                "expected \"nearby tickets:\""
+            ))
+        );
+        let numbers = records.expect("some numbers").unwrap();
+        assert_eq!(
+            numbers.parse_lines(|line| line.parse::<u32>().map_err(|_| anyhow!("not a number"))),
+            Err(ParseError::invalid(5, 1, "x", "not a number"))
+        );
+        assert_eq!(
+            records.expect("more numbers"),
+            Err(ParseError::unexpected_end(6, "more numbers"))
+        );
+        let mut records = Records::new("a\n\nb\n");
+        records.next();
+        assert_eq!(
+            records.finish(),
+            Err(ParseError::invalid(
+                3,
+                1,
+                "b",
+                "expected the end of the input"
+            ))
+        );
+    }
+}
@@ -0,0 +1,212 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Duration;
+
+use anyhow::{anyhow, Error, Result};
+use tracing::{info, info_span};
+
+use crate::answer::Answer;
+use crate::output::{print_parse, print_part};
+use crate::solution::Solution;
+use crate::timing::time;
+
+/// The answer to one part of a day and how long it took to solve, not counting parsing.
+#[derive(Debug)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+/// The outcome of parsing a day's input and solving some of its parts.
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub parse_elapsed: Duration,
+    /// Set when the input could not be parsed, in which case no parts were run.
+    pub parse_error: Option<Error>,
+    pub parts: Vec<PartReport>,
+}
+
+impl Report {
+    /// How long parsing and solving every part took altogether.
+    pub fn elapsed(&self) -> Duration {
+        self.parse_elapsed + self.parts.iter().map(|part| part.elapsed).sum::<Duration>()
+    }
+}
+
+/// Calls `f`, turning a panic into an error so that it only fails the step it happened in.
+pub fn catch_panic<T, F: FnOnce() -> Result<T>>(f: F) -> Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown cause");
+        Err(anyhow!("Panicked: {}", message))
+    })
+}
+
+/// Parses `input` once and then solves each of the requested `parts` with it and `params`, timing
+/// every step. A panic while parsing or solving a part is reported as that step's error.
+///
+/// Each step runs in a `parse`, `part1` or `part2` span inside a `solve` span for the day, and logs
+/// how it went when it finishes.
+pub fn solve<S: Solution>(input: &str, parts: &[u8], params: &S::Params) -> Report {
+    let _solve = info_span!("solve", day = S::DAY).entered();
+    let (parsed, parse_elapsed) = {
+        let _parse = info_span!("parse", bytes = input.len()).entered();
+        let (parsed, elapsed) = time(|| catch_panic(|| S::parse(input)));
+        match &parsed {
+            Ok(_) => info!(?elapsed, "parsed input"),
+            Err(error) => info!(?elapsed, error = %format!("{:#}", error), "failed to parse input"),
+        }
+        (parsed, elapsed)
+    };
+    let mut report = Report {
+        day: S::DAY,
+        parse_elapsed,
+        parse_error: None,
+        parts: Vec::new(),
+    };
+    match parsed {
+        Ok(input) => {
+            for &part in parts {
+                let _part = match part {
+                    1 => info_span!("part1"),
+                    _ => info_span!("part2"),
+                }
+                .entered();
+                let (answer, elapsed) = match part {
+                    1 => time(|| catch_panic(|| S::part1(&input, params))),
+                    _ => time(|| catch_panic(|| S::part2(&input, params))),
+                };
+                match &answer {
+                    Ok(answer) => info!(?elapsed, %answer, "solved"),
+                    Err(error) => {
+                        info!(?elapsed, error = %format!("{:#}", error), "failed to solve")
+                    }
+                }
+                report.parts.push(PartReport {
+                    part,
+                    answer,
+                    elapsed,
+                });
+            }
+        }
+        Err(error) => report.parse_error = Some(error),
+    }
+    report
+}
+
+/// Prints how long parsing took followed by each part's answer.
+pub fn print_report(report: &Report) {
+    print_parse(report.parse_error.as_ref(), report.parse_elapsed);
+    for part in &report.parts {
+        println!();
+        print_part(part.part, &part.answer, part.elapsed);
+    }
+}
+
+/// Solves both parts of a day with the puzzle's own parameters and prints the answers.
+pub fn run<S: Solution>(input: &str) {
+    print_report(&solve::<S>(input, &[1, 2], &S::Params::default()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anyhow::anyhow;
+
+    use crate::NoParams;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        type Input = Vec<u32>;
+        /// What to multiply the sum by.
+        type Params = u32;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            Ok(input
+                .split(',')
+                .map(|num| num.parse())
+                .collect::<Result<Vec<u32>, _>>()?)
+        }
+
+        fn part1(input: &Vec<u32>, factor: &u32) -> Result<Answer> {
+            Ok((input.iter().sum::<u32>() * factor).into())
+        }
+
+        fn part2(_: &Vec<u32>, _: &u32) -> Result<Answer> {
+            Err(anyhow!("No solution"))
+        }
+    }
+
+    #[test]
+    fn solves_requested_parts() {
+        let report = solve::<Sum>("1,2,3", &[1, 2], &1);
+        assert_eq!(report.day, 1);
+        assert!(report.parse_error.is_none());
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(
+            report.parts[0].answer.as_ref().unwrap(),
+            &Answer::Integer(6)
+        );
+        assert!(report.parts[1].answer.is_err());
+
+        let report = solve::<Sum>("1,2,3", &[2], &1);
+        assert_eq!(report.parts.len(), 1);
+        assert_eq!(report.parts[0].part, 2);
+    }
+
+    #[test]
+    fn solves_with_params() {
+        let report = solve::<Sum>("1,2,3", &[1], &10);
+        assert_eq!(
+            report.parts[0].answer.as_ref().unwrap(),
+            &Answer::Integer(60)
+        );
+    }
+
+    struct Panics;
+
+    impl Solution for Panics {
+        const DAY: u8 = 2;
+        type Input = Vec<u32>;
+        type Params = NoParams;
+
+        fn parse(input: &str) -> Result<Vec<u32>> {
+            Sum::parse(input)
+        }
+
+        fn part1(input: &Vec<u32>, _: &NoParams) -> Result<Answer> {
+            Ok(input[3].into())
+        }
+
+        fn part2(input: &Vec<u32>, _: &NoParams) -> Result<Answer> {
+            Sum::part1(input, &1)
+        }
+    }
+
+    #[test]
+    fn reports_panics_as_errors() {
+        let report = solve::<Panics>("1,2,3", &[1, 2], &NoParams {});
+        assert_eq!(
+            report.parts[0].answer.as_ref().unwrap_err().to_string(),
+            "Panicked: index out of bounds: the len is 3 but the index is 3"
+        );
+        assert_eq!(
+            report.parts[1].answer.as_ref().unwrap(),
+            &Answer::Integer(6)
+        );
+    }
+
+    #[test]
+    fn skips_parts_when_parsing_fails() {
+        let report = solve::<Sum>("1,two,3", &[1, 2], &1);
+        assert!(report.parse_error.is_some());
+        assert!(report.parts.is_empty());
+    }
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::answer::Answer;
+use crate::input::read_input;
+
+/// A day's puzzle solution.
+///
+/// The input is parsed once by [`Solution::parse`] and then shared by both parts, so parsing can
+/// be timed on its own.
+pub trait Solution {
+    /// The day of December the puzzle was released on.
+    const DAY: u8;
+
+    /// The puzzle input once it has been parsed.
+    type Input;
+
+    /// Numbers the puzzle text fixes, like how many turns a game lasts, which can be changed to
+    /// solve variants of the puzzle. The default is the puzzle's own. Days without any use
+    /// [`NoParams`].
+    type Params: Default + DeserializeOwned;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input, params: &Self::Params) -> Result<Answer>;
+
+    fn part2(input: &Self::Input, params: &Self::Params) -> Result<Answer>;
+
+    /// Reads and parses the input file at `path`.
+    fn parse_file<P: AsRef<Path>>(path: P) -> Result<Self::Input> {
+        Self::parse(&read_input(path)?)
+    }
+}
+
+/// The parameters of a puzzle with nothing to change. Setting any is an error.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
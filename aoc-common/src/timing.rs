@@ -0,0 +1,8 @@
+use std::time::{Duration, Instant};
+
+/// Calls `f` and returns its result along with how long it took.
+pub fn time<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let now = Instant::now();
+    let result = f();
+    (result, now.elapsed())
+}
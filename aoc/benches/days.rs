@@ -0,0 +1,88 @@
+//! Benchmarks every day's parse step and both parts against its real input.
+//!
+//! Save a baseline with `cargo bench -p aoc -- --save-baseline <name>` and compare a later run
+//! against it with `cargo bench -p aoc -- --baseline <name>`. Benchmarks can be filtered by id,
+//! e.g. `cargo bench -p aoc -- day15/part2`.
+
+use std::path::Path;
+
+use aoc_common::{read_input, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+
+/// Benchmarks a day whose parts take long enough that they are sampled fewer times, so that the
+/// whole suite finishes in a reasonable time.
+fn bench_slow_day<S: Solution>(c: &mut Criterion) {
+    bench::<S>(c, true);
+}
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    bench::<S>(c, false);
+}
+
+fn bench<S: Solution>(c: &mut Criterion, slow: bool) {
+    let name = format!("day{:02}", S::DAY);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(&name)
+        .join("input")
+        .join("input.txt");
+    let input = match read_input(&path) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Skipping {}: {:#}", name, error);
+            return;
+        }
+    };
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("Skipping {}: {:#}", name, error);
+            return;
+        }
+    };
+
+    let params = S::Params::default();
+
+    let mut group = c.benchmark_group(&name);
+    if slow {
+        group.sampling_mode(SamplingMode::Flat).sample_size(10);
+    }
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| {
+        b.iter(|| S::part1(black_box(&parsed), &params))
+    });
+    group.bench_function("part2", |b| {
+        b.iter(|| S::part2(black_box(&parsed), &params))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    days,
+    bench_day::<day01::Day01>,
+    bench_day::<day02::Day02>,
+    bench_day::<day03::Day03>,
+    bench_day::<day04::Day04>,
+    bench_day::<day05::Day05>,
+    bench_day::<day06::Day06>,
+    bench_day::<day07::Day07>,
+    bench_day::<day08::Day08>,
+    bench_day::<day09::Day09>,
+    bench_day::<day10::Day10>,
+    bench_day::<day11::Day11>,
+    bench_day::<day12::Day12>,
+    bench_day::<day13::Day13>,
+    bench_day::<day14::Day14>,
+    bench_slow_day::<day15::Day15>,
+    bench_day::<day16::Day16>,
+    bench_day::<day17::Day17>,
+    bench_day::<day18::Day18>,
+    bench_day::<day19::Day19>,
+    bench_day::<day20::Day20>,
+    bench_day::<day21::Day21>,
+    bench_day::<day22::Day22>,
+    bench_slow_day::<day23::Day23>,
+    bench_slow_day::<day24::Day24>,
+    bench_day::<day25::Day25>,
+);
+criterion_main!(days);
@@ -0,0 +1,230 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use serde::Deserialize;
+
+/// The event the puzzles are from.
+pub const YEAR: u16 = 2020;
+
+/// The environment variable that holds the session token, which takes precedence over the session
+/// file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// How to reach the puzzle site, from the `[client]` table of `aoc.toml`.
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ClientConfig {
+    /// The site to download inputs from.
+    pub url: String,
+    /// The file holding the session cookie of a logged in user, relative to the workspace root.
+    pub session_file: PathBuf,
+    /// The file recording every answer submitted and when the site will take another, relative to
+    /// the workspace root.
+    pub history_file: PathBuf,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            url: "https://adventofcode.com".to_string(),
+            session_file: ".session".into(),
+            history_file: ".submissions.toml".into(),
+        }
+    }
+}
+
+/// Downloads puzzle inputs as the user a session token belongs to. Inputs differ by user, so every
+/// request needs the token.
+pub struct Client {
+    url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(concat!(
+                "aoc-runner/",
+                env!("CARGO_PKG_VERSION"),
+                " by tyler@hallada.net"
+            ))
+            .timeout(Duration::from_secs(30))
+            .build();
+        Self {
+            url: url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            agent,
+        }
+    }
+
+    /// A client for the configured site, with the session token from `AOC_SESSION` or else the
+    /// session file.
+    pub fn from_config(config: &ClientConfig, root: &Path) -> Result<Self> {
+        let session = match env::var(SESSION_VAR) {
+            Ok(session) => session,
+            Err(_) => {
+                let path = root.join(&config.session_file);
+                fs::read_to_string(&path).with_context(|| {
+                    format!(
+                        "Failed to read the session token from {}. Set {} or save the session \
+                         cookie of a logged in browser there",
+                        path.display(),
+                        SESSION_VAR
+                    )
+                })?
+            }
+        };
+        Ok(Self::new(&config.url, &session))
+    }
+
+    pub fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", self.url, path);
+        let response = self.request("GET", &url).call();
+        read_response(&url, response)
+    }
+
+    /// Posts `form` as a URL encoded body.
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = format!("{}{}", self.url, path);
+        let response = self.request("POST", &url).send_form(form);
+        read_response(&url, response)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    /// Downloads the user's input for `day`.
+    pub fn input(&self, day: u8) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", YEAR, day))
+            .with_context(|| format!("Failed to download the input for day {}", day))
+    }
+}
+
+fn read_response(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+    match response {
+        Ok(response) => Ok(response.into_string()?),
+        Err(ureq::Error::Status(404, _)) => bail!("{} was not found", url),
+        Err(ureq::Error::Status(status @ (400 | 500), response)) => Err(anyhow!(
+            "The session token was rejected ({}): {}",
+            status,
+            response.into_string().unwrap_or_default().trim()
+        )),
+        Err(ureq::Error::Status(status, response)) => Err(anyhow!(
+            "{} failed with status {}: {}",
+            url,
+            status,
+            response.into_string().unwrap_or_default().trim()
+        )),
+        Err(error) => Err(anyhow!("Failed to reach {}: {}", url, error)),
+    }
+}
+
+/// Where a day's input is kept, which is where the runner reads it from too.
+pub fn input_path(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{:02}", day))
+        .join("input")
+        .join("input.txt")
+}
+
+/// Downloads the input for `day` into the day's `input/input.txt`, unless it is already there or
+/// `force` is set. Returns where the input is and whether it was downloaded.
+pub fn download_input(
+    client: &Client,
+    root: &Path,
+    day: u8,
+    force: bool,
+) -> Result<(PathBuf, bool)> {
+    let path = input_path(root, day);
+    if path.exists() && !force {
+        return Ok((path, false));
+    }
+    let input = client.input(day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    }
+    fs::write(&path, input).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok((path, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::mock_server::MockServer;
+
+    fn workspace() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+    }
+
+    #[test]
+    fn downloads_inputs() {
+        let server = MockServer::start(&workspace(), "secret", 0).unwrap();
+        let client = Client::new(server.url(), "secret\n");
+        assert_eq!(
+            client.input(15).unwrap(),
+            fs::read_to_string(workspace().join("day15/input/test.txt")).unwrap()
+        );
+        assert_eq!(
+            format!("{:#}", client.input(26).unwrap_err()),
+            format!(
+                "Failed to download the input for day 26: {}/2020/day/26/input was not found",
+                server.url()
+            )
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_sessions() {
+        let server = MockServer::start(&workspace(), "secret", 0).unwrap();
+        let error = Client::new(server.url(), "guess").input(1).unwrap_err();
+        assert!(format!("{:#}", error).contains("The session token was rejected (400)"));
+    }
+
+    #[test]
+    fn caches_downloads() {
+        let server = MockServer::start(&workspace(), "secret", 0).unwrap();
+        let client = Client::new(server.url(), "secret");
+        let root = tempfile::tempdir().unwrap();
+
+        let (path, downloaded) = download_input(&client, root.path(), 1, false).unwrap();
+        assert!(downloaded);
+        assert_eq!(path, root.path().join("day01/input/input.txt"));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            fs::read_to_string(workspace().join("day01/input/test.txt")).unwrap()
+        );
+
+        fs::write(&path, "edited").unwrap();
+        let (_, downloaded) = download_input(&client, root.path(), 1, false).unwrap();
+        assert!(!downloaded);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "edited");
+
+        let (_, downloaded) = download_input(&client, root.path(), 1, true).unwrap();
+        assert!(downloaded);
+        assert_ne!(fs::read_to_string(&path).unwrap(), "edited");
+    }
+
+    #[test]
+    fn reads_the_session_file() {
+        let root = tempfile::tempdir().unwrap();
+        let config = ClientConfig {
+            session_file: "session.txt".into(),
+            ..ClientConfig::default()
+        };
+        if env::var(SESSION_VAR).is_err() {
+            assert!(Client::from_config(&config, root.path()).is_err());
+            fs::write(root.path().join("session.txt"), "abc\n").unwrap();
+            assert_eq!(
+                Client::from_config(&config, root.path()).unwrap().session,
+                "abc"
+            );
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::ops::Range;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+use crate::days::Day;
+use crate::generate::Generator;
+use crate::reference::Reference;
+use crate::verify::part_answer;
+
+/// The result of solving one part of one generated input with both the reference and the real
+/// solution.
+#[derive(Debug)]
+pub struct Comparison {
+    pub day: u8,
+    pub seed: u64,
+    pub part: u8,
+    pub reference: Result<Answer>,
+    pub actual: Result<Answer>,
+}
+
+impl Comparison {
+    pub fn matched(&self) -> bool {
+        matches!((&self.reference, &self.actual), (Ok(reference), Ok(actual)) if reference == actual)
+    }
+}
+
+impl std::fmt::Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} part {} (seed {}): ",
+            self.day, self.part, self.seed
+        )?;
+        match (&self.reference, &self.actual) {
+            (Ok(reference), Ok(actual)) if reference == actual => write!(f, "ok"),
+            (Ok(reference), Ok(actual)) => {
+                write!(f, "reference gave {}, got {}", reference, actual)
+            }
+            (Ok(reference), Err(error)) => {
+                write!(f, "reference gave {}, failed: {:#}", reference, error)
+            }
+            (Err(error), _) => write!(f, "reference failed: {:#}", error),
+        }
+    }
+}
+
+/// Generates an input of `size` from each seed and solves every part the reference can solve with
+/// both the reference and the real solution, running the real one with the reference's parameters.
+pub fn compare_day(
+    day: &Day,
+    reference: &Reference,
+    generator: &Generator,
+    size: usize,
+    seeds: Range<u64>,
+) -> Result<Vec<Comparison>> {
+    let parts: Vec<u8> = (1..=2)
+        .filter(|&part| reference.part(part).is_some())
+        .collect();
+    let mut comparisons = Vec::new();
+    for seed in seeds {
+        let input = generator.generate(size, seed)?;
+        let report = (day.solve)(&input, &parts, &reference.params);
+        for &part in &parts {
+            let solve = reference
+                .part(part)
+                .expect("only parts with a reference are run");
+            comparisons.push(Comparison {
+                day: day.number,
+                seed,
+                part,
+                reference: solve(&input).map_err(|error| anyhow!("{:#}", error)),
+                actual: part_answer(&report, part),
+            });
+        }
+    }
+    Ok(comparisons)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{days, generate, reference};
+
+    #[test]
+    fn formats_comparisons() {
+        let mut comparison = Comparison {
+            day: 10,
+            seed: 3,
+            part: 2,
+            reference: Ok(Answer::Integer(8)),
+            actual: Ok(Answer::Integer(8)),
+        };
+        assert!(comparison.matched());
+        assert_eq!(comparison.to_string(), "Day 10 part 2 (seed 3): ok");
+
+        comparison.actual = Ok(Answer::Integer(7));
+        assert!(!comparison.matched());
+        assert_eq!(
+            comparison.to_string(),
+            "Day 10 part 2 (seed 3): reference gave 8, got 7"
+        );
+
+        comparison.actual = Err(anyhow!("No solution"));
+        assert_eq!(
+            comparison.to_string(),
+            "Day 10 part 2 (seed 3): reference gave 8, failed: No solution"
+        );
+
+        comparison.reference = Err(anyhow!("Too many adapters"));
+        assert!(!comparison.matched());
+        assert_eq!(
+            comparison.to_string(),
+            "Day 10 part 2 (seed 3): reference failed: Too many adapters"
+        );
+    }
+
+    #[test]
+    fn matches_every_reference() {
+        for day in days::all() {
+            let reference = reference::find(day.number).unwrap();
+            let generator = generate::find(day.number).unwrap();
+            // Part 2 of day 24 takes seconds in a debug build whatever the input.
+            let seeds = if day.number == 24 { 0..1 } else { 0..3 };
+            for comparison in
+                compare_day(&day, &reference, &generator, reference.size, seeds).unwrap()
+            {
+                assert!(comparison.matched(), "{}", comparison);
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use toml::Table;
+
+use crate::client::ClientConfig;
+use crate::params::Params;
+
+/// The file in the workspace root configuring the runner.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Everything `aoc.toml` sets: how to reach the puzzle site in its `[client]` table, and the
+/// parameters of any of the days in tables named after them.
+#[derive(Debug, Default)]
+pub struct Config {
+    pub client: ClientConfig,
+    pub params: Params,
+}
+
+impl Config {
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut table: Table = toml::from_str(s)?;
+        let client = match table.remove("client") {
+            Some(client) => client.try_into().context("Invalid [client] table")?,
+            None => ClientConfig::default(),
+        };
+        Ok(Self {
+            client,
+            params: Params::from_table(table)?,
+        })
+    }
+
+    /// Loads the config file at `path`, or the defaults if there isn't one.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file: {}", path.display()))?;
+        Self::parse(&s).with_context(|| format!("Failed to parse config file: {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::days;
+
+    #[test]
+    fn parses_config() {
+        let config = Config::parse(
+            r#"
+            [client]
+            url = "http://127.0.0.1:8020"
+
+            [day09]
+            preamble = 5
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.client.url, "http://127.0.0.1:8020");
+        assert_eq!(config.client.session_file, Path::new(".session"));
+        assert_eq!(
+            config.params.for_day(&days::find(9).unwrap())["preamble"],
+            toml::Value::Integer(5)
+        );
+        assert!(Config::parse("[client]\ntoken = \"abc\"\n").is_err());
+    }
+
+    #[test]
+    fn loads_workspace_config() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let config = Config::load(root.join(CONFIG_FILE)).unwrap();
+        assert_eq!(config.client, ClientConfig::default());
+    }
+}
@@ -0,0 +1,101 @@
+use anyhow::{anyhow, Result};
+use aoc_common::{solve, Report, Solution};
+use toml::Table;
+
+use crate::repl::{self, Session};
+
+/// Parses a day's input and then solves the requested parts of it with the parameters in the
+/// table. Parameters left out keep the puzzle's own values.
+pub type Solver = fn(&str, &[u8], &Table) -> Report;
+
+/// Parses a day's input and starts a REPL session exploring it with the parameters in the table
+/// set, returning the session and what to print about parsing.
+pub type Repl = fn(&str, Table) -> Result<(Box<dyn Session>, String)>;
+
+/// Every day's solution that the runner knows how to call.
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    /// Checks that a table only sets the day's parameters, to values of the right type.
+    pub check_params: fn(&Table) -> Result<()>,
+    pub repl: Repl,
+}
+
+impl Day {
+    /// The name of the day's crate, which is also its directory in the workspace.
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.number)
+    }
+}
+
+pub fn parse_params<S: Solution>(params: &Table) -> Result<S::Params> {
+    params
+        .clone()
+        .try_into()
+        .map_err(|error| anyhow!("Invalid parameters for day {}: {}", S::DAY, error))
+}
+
+fn solve_with_params<S: Solution>(input: &str, parts: &[u8], params: &Table) -> Report {
+    match parse_params::<S>(params) {
+        Ok(params) => solve::<S>(input, parts, &params),
+        Err(error) => Report {
+            day: S::DAY,
+            parse_elapsed: Default::default(),
+            parse_error: Some(error),
+            parts: Vec::new(),
+        },
+    }
+}
+
+fn check_params<S: Solution>(params: &Table) -> Result<()> {
+    parse_params::<S>(params).map(drop)
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        day!($solution, Vec::new)
+    };
+    // A day with views of its parsed input to print in the REPL.
+    ($solution:ty, $views:path) => {
+        Day {
+            number: <$solution as Solution>::DAY,
+            solve: solve_with_params::<$solution>,
+            check_params: check_params::<$solution>,
+            repl: |input, params| repl::start::<$solution>(input, params, $views()),
+        }
+    };
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        day!(day01::Day01),
+        day!(day02::Day02),
+        day!(day03::Day03),
+        day!(day04::Day04),
+        day!(day05::Day05),
+        day!(day06::Day06),
+        day!(day07::Day07, repl::day07::views),
+        day!(day08::Day08),
+        day!(day09::Day09),
+        day!(day10::Day10),
+        day!(day11::Day11),
+        day!(day12::Day12),
+        day!(day13::Day13),
+        day!(day14::Day14),
+        day!(day15::Day15),
+        day!(day16::Day16, repl::day16::views),
+        day!(day17::Day17),
+        day!(day18::Day18),
+        day!(day19::Day19),
+        day!(day20::Day20),
+        day!(day21::Day21, repl::day21::views),
+        day!(day22::Day22),
+        day!(day23::Day23),
+        day!(day24::Day24),
+        day!(day25::Day25),
+    ]
+}
+
+pub fn find(number: u8) -> Option<Day> {
+    all().into_iter().find(|day| day.number == number)
+}
@@ -0,0 +1,186 @@
+use anyhow::Result;
+use aoc_common::{Answer, Report};
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How the runner prints its results.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Each day's answers as they are found, followed by a summary table.
+    Text,
+    /// A JSON array with one object per part.
+    Json,
+    /// A CSV table with a header and one row per part.
+    Csv,
+}
+
+/// The outcome of one part, flattened for machine-readable output. Times are in nanoseconds.
+/// When a part fails, `answer` is empty and `error` says why, and `solve_ns` is still how long it
+/// ran. It is only empty for parts that never ran because the input failed to parse.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub error: Option<String>,
+    pub parse_ns: u64,
+    pub solve_ns: Option<u64>,
+}
+
+/// Flattens the reports into one row per part. A day whose input failed to parse gets a failed
+/// row for each of the requested `parts`.
+pub fn rows(reports: &[Report], parts: &[u8]) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for report in reports {
+        let parse_ns = report.parse_elapsed.as_nanos() as u64;
+        if let Some(error) = &report.parse_error {
+            for &part in parts {
+                rows.push(Row {
+                    day: report.day,
+                    part,
+                    answer: None,
+                    error: Some(format!("Failed to parse input: {:#}", error)),
+                    parse_ns,
+                    solve_ns: None,
+                });
+            }
+        }
+        for part in &report.parts {
+            let (answer, error) = match &part.answer {
+                Ok(answer) => (Some(answer.clone()), None),
+                Err(error) => (None, Some(format!("{:#}", error))),
+            };
+            rows.push(Row {
+                day: report.day,
+                part: part.part,
+                solve_ns: Some(part.elapsed.as_nanos() as u64),
+                answer,
+                error,
+                parse_ns,
+            });
+        }
+    }
+    rows
+}
+
+pub fn to_json(rows: &[Row]) -> Result<String> {
+    Ok(serde_json::to_string_pretty(rows)?)
+}
+
+pub fn to_csv(rows: &[Row]) -> Result<String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+    use aoc_common::PartReport;
+
+    fn reports() -> Vec<Report> {
+        vec![
+            Report {
+                day: 21,
+                parse_elapsed: Duration::from_nanos(1500),
+                parse_error: None,
+                parts: vec![
+                    PartReport {
+                        part: 1,
+                        answer: Ok(Answer::Integer(5)),
+                        elapsed: Duration::from_nanos(200),
+                    },
+                    PartReport {
+                        part: 2,
+                        answer: Ok(Answer::Text("mxmxvkd,sqjhc,fvjkl".to_string())),
+                        elapsed: Duration::from_nanos(300),
+                    },
+                ],
+            },
+            Report {
+                day: 23,
+                parse_elapsed: Duration::from_nanos(100),
+                parse_error: Some(anyhow!("Input is empty")),
+                parts: vec![],
+            },
+            Report {
+                day: 13,
+                parse_elapsed: Duration::from_nanos(400),
+                parse_error: None,
+                parts: vec![PartReport {
+                    part: 1,
+                    answer: Err(anyhow!("No buses are in service")),
+                    elapsed: Duration::from_nanos(700),
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn flattens_reports() {
+        let rows = rows(&reports(), &[1, 2]);
+        assert_eq!(rows.len(), 5);
+        assert_eq!(
+            rows[0],
+            Row {
+                day: 21,
+                part: 1,
+                answer: Some(Answer::Integer(5)),
+                error: None,
+                parse_ns: 1500,
+                solve_ns: Some(200),
+            }
+        );
+        assert_eq!(
+            rows[3],
+            Row {
+                day: 23,
+                part: 2,
+                answer: None,
+                error: Some("Failed to parse input: Input is empty".to_string()),
+                parse_ns: 100,
+                solve_ns: None,
+            }
+        );
+        assert_eq!(
+            rows[4],
+            Row {
+                day: 13,
+                part: 1,
+                answer: None,
+                error: Some("No buses are in service".to_string()),
+                parse_ns: 400,
+                solve_ns: Some(700),
+            }
+        );
+    }
+
+    #[test]
+    fn formats_json() {
+        let json = to_json(&rows(&reports(), &[1, 2])).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value[0]["answer"], 5);
+        assert_eq!(value[1]["answer"], "mxmxvkd,sqjhc,fvjkl");
+        assert_eq!(value[2]["answer"], serde_json::Value::Null);
+        assert_eq!(value[2]["error"], "Failed to parse input: Input is empty");
+    }
+
+    #[test]
+    fn formats_csv() {
+        assert_eq!(
+            to_csv(&rows(&reports(), &[1, 2])).unwrap(),
+            "day,part,answer,error,parse_ns,solve_ns\n\
+             21,1,5,,1500,200\n\
+             21,2,\"mxmxvkd,sqjhc,fvjkl\",,1500,300\n\
+             23,1,,Failed to parse input: Input is empty,100,\n\
+             23,2,,Failed to parse input: Input is empty,100,\n\
+             13,1,,No buses are in service,400,700\n"
+        );
+    }
+}
@@ -0,0 +1,1326 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use anyhow::{anyhow, bail, ensure, Result};
+use rand::rngs::StdRng;
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, SeedableRng};
+
+/// Writes random puzzle inputs for one day, of any size the puzzle allows, so the solutions can be
+/// stress tested and benchmarked on inputs far larger than the real ones.
+pub struct Generator {
+    pub day: u8,
+    /// What the size of the day's input counts, like `bag rules`.
+    pub unit: &'static str,
+    /// About the size of the day's real input.
+    pub default_size: usize,
+    generate: fn(&mut StdRng, usize) -> Result<String>,
+}
+
+impl Generator {
+    /// Generates an input of `size` from `seed`. The same seed and size always give the same input.
+    pub fn generate(&self, size: usize, seed: u64) -> Result<String> {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+macro_rules! generator {
+    ($day:expr, $generate:ident, $default_size:expr, $unit:expr) => {
+        Generator {
+            day: $day,
+            unit: $unit,
+            default_size: $default_size,
+            generate: $generate,
+        }
+    };
+}
+
+pub fn all() -> Vec<Generator> {
+    vec![
+        generator!(1, day01, 200, "expense entries"),
+        generator!(2, day02, 1000, "passwords"),
+        generator!(3, day03, 323, "rows"),
+        generator!(4, day04, 290, "passports"),
+        generator!(5, day05, 800, "boarding passes"),
+        generator!(6, day06, 490, "groups"),
+        generator!(7, day07, 594, "bag rules"),
+        generator!(8, day08, 650, "instructions"),
+        generator!(9, day09, 1000, "numbers"),
+        generator!(10, day10, 100, "adapters"),
+        generator!(11, day11, 95, "rows and columns"),
+        generator!(12, day12, 780, "instructions"),
+        generator!(13, day13, 70, "bus slots"),
+        generator!(14, day14, 580, "instructions"),
+        generator!(15, day15, 7, "starting numbers"),
+        generator!(16, day16, 240, "nearby tickets"),
+        generator!(17, day17, 8, "rows and columns"),
+        generator!(18, day18, 370, "expressions"),
+        generator!(19, day19, 450, "messages"),
+        generator!(20, day20, 12, "tiles per side"),
+        generator!(21, day21, 40, "foods"),
+        generator!(22, day22, 25, "cards per player"),
+        generator!(23, day23, 9, "cups"),
+        generator!(24, day24, 500, "tile paths"),
+        generator!(25, day25, 2, "public keys"),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Generator> {
+    all().into_iter().find(|generator| generator.day == day)
+}
+
+/// A made up lowercase word of `syllables` syllables.
+fn word(rng: &mut StdRng, syllables: usize) -> String {
+    const CONSONANTS: &[u8] = b"bcdfghjklmnprstvxz";
+    const VOWELS: &[u8] = b"aeiou";
+    (0..syllables)
+        .flat_map(|_| {
+            [
+                *CONSONANTS.choose(rng).expect("non-empty") as char,
+                *VOWELS.choose(rng).expect("non-empty") as char,
+            ]
+        })
+        .collect()
+}
+
+/// `count` different made up words.
+fn unique_words(rng: &mut StdRng, count: usize, taken: &mut HashSet<String>) -> Vec<String> {
+    let mut words = Vec::new();
+    while words.len() < count {
+        let syllables = rng.gen_range(2..=4);
+        let word = word(rng, syllables);
+        if taken.insert(word.clone()) {
+            words.push(word);
+        }
+    }
+    words
+}
+
+fn lines<T: ToString>(items: &[T]) -> String {
+    items
+        .iter()
+        .map(|item| format!("{}\n", item.to_string()))
+        .collect()
+}
+
+/// Plants exactly one pair and one triple summing to 2020 among numbers that can't be part of any
+/// other. The fillers are all over 1010, so no two of them make a pair and no filler is in a
+/// triple with another filler.
+fn day01(rng: &mut StdRng, size: usize) -> Result<String> {
+    ensure!(size >= 5, "Day 1 needs at least 5 expense entries");
+    let sums_to_2020_once = |numbers: &[i32]| {
+        let mut pairs = 0;
+        let mut triples = 0;
+        for (i, a) in numbers.iter().enumerate() {
+            for (j, b) in numbers.iter().enumerate().skip(i + 1) {
+                pairs += (a + b == 2020) as usize;
+                triples += numbers[j + 1..]
+                    .iter()
+                    .filter(|&&c| a + b + c == 2020)
+                    .count();
+            }
+        }
+        // Doubled numbers would let a solver reuse one entry.
+        let doubled = numbers.iter().enumerate().any(|(i, a)| {
+            2 * a == 2020
+                || numbers
+                    .iter()
+                    .enumerate()
+                    .any(|(j, b)| i != j && 2 * a + b == 2020)
+        });
+        pairs == 1 && triples == 1 && !doubled
+    };
+    let planted = loop {
+        let a = rng.gen_range(1..=300);
+        let c = rng.gen_range(301..=1009);
+        let d = rng.gen_range(301..=1009);
+        let e = 2020 - c - d;
+        let planted = vec![a, 2020 - a, c, d, e];
+        if (301..=1009).contains(&e) && sums_to_2020_once(&planted) {
+            break planted;
+        }
+    };
+    let mut taken = HashSet::new();
+    for a in &planted {
+        taken.insert(2020 - a);
+        for b in &planted {
+            taken.insert(2020 - a - b);
+        }
+    }
+    let fillers: Vec<i32> = (1011..=2020).filter(|n| !taken.contains(n)).collect();
+    let mut numbers = planted;
+    while numbers.len() < size {
+        numbers.push(*fillers.choose(rng).expect("non-empty"));
+    }
+    numbers.shuffle(rng);
+    Ok(lines(&numbers))
+}
+
+fn day02(rng: &mut StdRng, size: usize) -> Result<String> {
+    let entries: Vec<String> = (0..size)
+        .map(|_| {
+            let low = rng.gen_range(1..=10);
+            let high = rng.gen_range(low + 1..=low + 10);
+            let letter = rng.gen_range(b'a'..=b'z') as char;
+            let length = rng.gen_range(high..=high + 8);
+            let password: String = (0..length)
+                .map(|_| {
+                    if rng.gen_bool(0.3) {
+                        letter
+                    } else {
+                        rng.gen_range(b'a'..=b'z') as char
+                    }
+                })
+                .collect();
+            format!("{}-{} {}: {}", low, high, letter, password)
+        })
+        .collect();
+    Ok(lines(&entries))
+}
+
+fn day03(rng: &mut StdRng, size: usize) -> Result<String> {
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..31)
+                .map(|_| if rng.gen_bool(0.25) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    Ok(lines(&rows))
+}
+
+/// Each field is usually present and valid, but is sometimes left out or given a value that only
+/// the strict validation of part 2 rejects.
+fn day04(rng: &mut StdRng, size: usize) -> Result<String> {
+    fn digits(rng: &mut StdRng, count: usize) -> String {
+        (0..count)
+            .map(|_| rng.gen_range(b'0'..=b'9') as char)
+            .collect()
+    }
+    fn hex(rng: &mut StdRng) -> String {
+        (0..6)
+            .map(|_| *b"0123456789abcdef".choose(rng).expect("non-empty") as char)
+            .collect()
+    }
+    const EYE_COLORS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    let passports: Vec<String> = (0..size)
+        .map(|_| {
+            let mut fields = Vec::new();
+            for name in ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"] {
+                let present = if name == "cid" { 0.5 } else { 0.93 };
+                if !rng.gen_bool(present) {
+                    continue;
+                }
+                let valid = rng.gen_bool(0.85);
+                let value = match (name, valid) {
+                    ("byr", true) => rng.gen_range(1920..=2002).to_string(),
+                    ("byr", false) => rng.gen_range(1900..=2030).to_string(),
+                    ("iyr", true) => rng.gen_range(2010..=2020).to_string(),
+                    ("iyr", false) => rng.gen_range(2000..=2030).to_string(),
+                    ("eyr", true) => rng.gen_range(2020..=2030).to_string(),
+                    ("eyr", false) => rng.gen_range(2010..=2040).to_string(),
+                    ("hgt", true) if rng.gen_bool(0.5) => format!("{}cm", rng.gen_range(150..=193)),
+                    ("hgt", true) => format!("{}in", rng.gen_range(59..=76)),
+                    ("hgt", false) => rng.gen_range(50..=200).to_string(),
+                    ("hcl", true) => format!("#{}", hex(rng)),
+                    ("hcl", false) => hex(rng),
+                    ("ecl", true) => EYE_COLORS.choose(rng).expect("non-empty").to_string(),
+                    ("ecl", false) => word(rng, 1),
+                    ("pid", true) => digits(rng, 9),
+                    ("pid", false) => {
+                        let count = *[8, 10].choose(rng).expect("non-empty");
+                        digits(rng, count)
+                    }
+                    _ => rng.gen_range(100..=350).to_string(),
+                };
+                fields.push(format!("{}:{}", name, value));
+            }
+            fields.shuffle(rng);
+            let mut passport = String::new();
+            for (index, field) in fields.iter().enumerate() {
+                if index > 0 {
+                    passport.push(if rng.gen_bool(0.3) { '\n' } else { ' ' });
+                }
+                passport.push_str(field);
+            }
+            passport
+        })
+        .collect();
+    Ok(passports.join("\n\n") + "\n")
+}
+
+/// A run of consecutive seats with one missing from the middle, which is the seat part 2 finds.
+fn day05(rng: &mut StdRng, size: usize) -> Result<String> {
+    ensure!(
+        (2..=1022).contains(&size),
+        "Day 5 has room for 2 to 1022 boarding passes"
+    );
+    let first = rng.gen_range(0..=1023 - size);
+    let missing = first + rng.gen_range(1..size);
+    let mut passes: Vec<String> = (first..=first + size)
+        .filter(|&id| id != missing)
+        .map(|id| {
+            (0..10)
+                .rev()
+                .map(|bit| match (bit >= 3, id >> bit & 1 == 1) {
+                    (true, false) => 'F',
+                    (true, true) => 'B',
+                    (false, false) => 'L',
+                    (false, true) => 'R',
+                })
+                .collect()
+        })
+        .collect();
+    passes.shuffle(rng);
+    Ok(lines(&passes))
+}
+
+fn day06(rng: &mut StdRng, size: usize) -> Result<String> {
+    let groups: Vec<String> = (0..size)
+        .map(|_| {
+            let common: Vec<char> = ('a'..='z').filter(|_| rng.gen_bool(0.1)).collect();
+            let people: Vec<String> = (0..rng.gen_range(1..=5))
+                .map(|_| {
+                    let mut answers = common.clone();
+                    answers
+                        .extend(('a'..='z').filter(|c| !common.contains(c) && rng.gen_bool(0.2)));
+                    if answers.is_empty() {
+                        answers.push(rng.gen_range(b'a'..=b'z') as char);
+                    }
+                    answers.shuffle(rng);
+                    answers.into_iter().collect()
+                })
+                .collect();
+            people.join("\n")
+        })
+        .collect();
+    Ok(groups.join("\n\n") + "\n")
+}
+
+/// The most bags shiny gold may hold, so part 2's answer fits in a `u32`.
+const MAX_INNER_BAGS: u64 = 1_000_000_000;
+
+/// Puts the bags in a random order and only lets each bag contain bags after it, which keeps the
+/// rules acyclic. Shiny gold goes in the middle so that there are bags both outside and inside it.
+/// Only shiny gold and the bags after it, which it may contain, are held to [`MAX_INNER_BAGS`].
+fn day07(rng: &mut StdRng, size: usize) -> Result<String> {
+    const ADJECTIVES: [&str; 18] = [
+        "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+        "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+    ];
+    const COLORS: [&str; 33] = [
+        "aqua",
+        "beige",
+        "black",
+        "blue",
+        "bronze",
+        "brown",
+        "chartreuse",
+        "coral",
+        "crimson",
+        "cyan",
+        "fuchsia",
+        "gold",
+        "gray",
+        "green",
+        "indigo",
+        "lavender",
+        "lime",
+        "magenta",
+        "maroon",
+        "olive",
+        "orange",
+        "plum",
+        "purple",
+        "red",
+        "salmon",
+        "silver",
+        "tan",
+        "teal",
+        "tomato",
+        "turquoise",
+        "violet",
+        "white",
+        "yellow",
+    ];
+    ensure!(size >= 1, "Day 7 needs at least the shiny gold bag's rule");
+    let mut colors: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLORS
+                .iter()
+                .map(move |color| format!("{} {}", adjective, color))
+        })
+        .filter(|color| color != "shiny gold")
+        .collect();
+    colors.shuffle(rng);
+    colors.truncate(size - 1);
+    let mut taken: HashSet<String> = colors.iter().cloned().collect();
+    while colors.len() < size - 1 {
+        let color = format!("{} {}", word(rng, 2), word(rng, 2));
+        if taken.insert(color.clone()) {
+            colors.push(color);
+        }
+    }
+    let shiny_gold = rng.gen_range(size / 3..=size - 1 - size / 3);
+    colors.insert(shiny_gold, "shiny gold".to_owned());
+
+    let mut inner_bags: Vec<u64> = vec![0; size];
+    let mut rules = Vec::with_capacity(size);
+    for container in (0..size).rev() {
+        // Only the next few bags are candidates, so that bags are nested deeply and shiny gold has
+        // plenty of bags outside it.
+        let later = (size - container - 1).min(50);
+        let kinds = if rng.gen_bool(0.15) {
+            0
+        } else {
+            rng.gen_range(1..=4).min(later)
+        };
+        let mut contents = Vec::new();
+        for inner in index::sample(rng, later, kinds) {
+            let inner = container + 1 + inner;
+            let total = |quantity: u64| {
+                quantity
+                    .saturating_mul(inner_bags[inner].saturating_add(1))
+                    .saturating_add(inner_bags[container])
+            };
+            let mut quantity = rng.gen_range(1..=5);
+            while quantity > 0 && container >= shiny_gold && total(quantity) > MAX_INNER_BAGS {
+                quantity -= 1;
+            }
+            if quantity > 0 {
+                inner_bags[container] = total(quantity);
+                let plural = if quantity == 1 { "" } else { "s" };
+                contents.push(format!("{} {} bag{}", quantity, colors[inner], plural));
+            }
+        }
+        if contents.is_empty() {
+            rules.push(format!("{} bags contain no other bags.", colors[container]));
+        } else {
+            rules.push(format!(
+                "{} bags contain {}.",
+                colors[container],
+                contents.join(", ")
+            ));
+        }
+    }
+    rules.shuffle(rng);
+    Ok(lines(&rules))
+}
+
+/// Splits the program in two. The instructions after the split run straight to the end. The ones
+/// before it are visited in a random order of short runs and then loop, so the only way out of
+/// the loop is flipping the `jmp` just before the split into a `nop`. Every `nop` before the split
+/// jumps back before it when flipped.
+fn day08(rng: &mut StdRng, size: usize) -> Result<String> {
+    use day08::Operation;
+
+    ensure!(size >= 1, "Day 8 needs at least 1 instruction");
+    let split = rng
+        .gen_range(size.div_ceil(4)..=(3 * size).div_ceil(4))
+        .max(1);
+    let mut runs = Vec::new();
+    let mut start = 0;
+    while start < split {
+        let end = (start + rng.gen_range(1..=6)).min(split);
+        runs.push(start..end);
+        start = end;
+    }
+    runs[1..].shuffle(rng);
+    let order: Vec<usize> = runs.into_iter().flatten().collect();
+    let loop_start = order[rng.gen_range(0..split)];
+
+    let mut program = Vec::with_capacity(size);
+    program.resize_with(size, || Operation::Nop(0));
+    for (index, &pointer) in order.iter().enumerate() {
+        let next = order.get(index + 1).copied().unwrap_or(loop_start);
+        program[pointer] = if next == pointer + 1 && rng.gen_bool(0.8) {
+            if rng.gen_bool(0.75) {
+                Operation::Acc(rng.gen_range(-50..=50))
+            } else {
+                Operation::Nop(rng.gen_range(0..split) as i32 - pointer as i32)
+            }
+        } else {
+            Operation::Jmp(next as i32 - pointer as i32)
+        };
+    }
+    for (pointer, operation) in program.iter_mut().enumerate().skip(split) {
+        let to_end = (size - pointer) as i32;
+        *operation = match rng.gen_range(0..10) {
+            0..=4 => Operation::Acc(rng.gen_range(-50..=50)),
+            5..=7 => Operation::Nop(rng.gen_range(-(pointer as i32)..=to_end)),
+            _ => Operation::Jmp(rng.gen_range(1..=to_end.min(10))),
+        };
+    }
+    Ok(lines(&program))
+}
+
+/// The furthest into day 9's input the invalid number goes. Every number before it is a sum of two
+/// before that, so they double about once per preamble and would overflow a `usize` at around 1500.
+const MAX_INVALID_POSITION: usize = 1000;
+
+/// Builds each number from two of the smallest numbers before it until the invalid number, which is
+/// instead the sum of a contiguous run. Only the numbers up to the invalid one have to be sums, so
+/// the rest reuse earlier numbers to stay small however many there are.
+fn day09(rng: &mut StdRng, size: usize) -> Result<String> {
+    use day09::PREAMBLE;
+
+    ensure!(
+        size > PREAMBLE,
+        "Day 9 needs more numbers than its preamble of {}",
+        PREAMBLE
+    );
+    let mut numbers: Vec<usize> = index::sample(rng, PREAMBLE * 2, PREAMBLE)
+        .into_iter()
+        .map(|number| number + 1)
+        .collect();
+    let last_invalid = size.min(MAX_INVALID_POSITION);
+    let invalid = rng.gen_range(PREAMBLE.max(last_invalid / 2)..last_invalid);
+    for position in PREAMBLE..size {
+        if position > invalid {
+            let earlier = rng.gen_range(0..invalid);
+            numbers.push(numbers[earlier]);
+            continue;
+        }
+        let mut window: Vec<usize> = numbers[position - PREAMBLE..].to_vec();
+        window.sort_unstable();
+        window.dedup();
+        let number = if position == invalid {
+            let sums: HashSet<usize> = window
+                .iter()
+                .flat_map(|a| window.iter().filter(move |&b| b != a).map(move |b| a + b))
+                .collect();
+            (0..1000)
+                .find_map(|_| {
+                    let length = rng.gen_range(2..=position.min(17));
+                    let start = rng.gen_range(0..=position - length);
+                    let sum = numbers[start..start + length].iter().sum();
+                    Some(sum).filter(|sum| !sums.contains(sum))
+                })
+                .ok_or_else(|| anyhow!("Couldn't find a run to sum to the invalid number"))?
+        } else {
+            let smallest = window.len().min(3);
+            let picks = index::sample(rng, smallest, 2);
+            window[picks.index(0)] + window[picks.index(1)]
+        };
+        numbers.push(number);
+    }
+    Ok(lines(&numbers))
+}
+
+/// The most arrangements of adapters part 2 may find.
+const MAX_ARRANGEMENTS: u64 = 1_000_000_000_000_000;
+
+/// Steps between adapters by 1 or 3 jolts like the real inputs, forcing a step of 3 whenever
+/// another 1 would take the number of arrangements past [`MAX_ARRANGEMENTS`].
+fn day10(rng: &mut StdRng, size: usize) -> Result<String> {
+    // The number of arrangements reaching each of the three jolts up to the latest adapter, which
+    // is 0 for jolts without an adapter.
+    let mut arrangements: [u64; 3] = [0, 0, 1];
+    let mut jolts = 0;
+    let mut adapters = Vec::with_capacity(size);
+    for _ in 0..size {
+        let stepped_by_one = arrangements.iter().sum::<u64>();
+        if rng.gen_bool(0.7) && stepped_by_one <= MAX_ARRANGEMENTS {
+            jolts += 1;
+            arrangements = [arrangements[1], arrangements[2], stepped_by_one];
+        } else {
+            jolts += 3;
+            arrangements = [0, 0, arrangements[2]];
+        }
+        adapters.push(jolts);
+    }
+    adapters.shuffle(rng);
+    Ok(lines(&adapters))
+}
+
+fn day11(rng: &mut StdRng, size: usize) -> Result<String> {
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(0.75) { 'L' } else { '.' })
+                .collect()
+        })
+        .collect();
+    Ok(lines(&rows))
+}
+
+fn day12(rng: &mut StdRng, size: usize) -> Result<String> {
+    let instructions: Vec<String> = (0..size)
+        .map(|_| match rng.gen_range(0..10) {
+            0..=2 => format!("F{}", rng.gen_range(1..=100)),
+            3..=6 => format!(
+                "{}{}",
+                ['N', 'S', 'E', 'W'].choose(rng).expect("non-empty"),
+                rng.gen_range(1..=5)
+            ),
+            _ => format!(
+                "{}{}",
+                ['L', 'R'].choose(rng).expect("non-empty"),
+                [90, 180, 270].choose(rng).expect("non-empty")
+            ),
+        })
+        .collect();
+    Ok(lines(&instructions))
+}
+
+/// The most the bus ids may multiply to, so part 2's timestamps fit in an `i64`.
+const MAX_BUS_PRODUCT: i64 = 1_000_000_000_000_000_000;
+
+/// Buses have different prime ids, so part 2 always has an answer, and the first slot always has
+/// a bus. After the ids multiply to [`MAX_BUS_PRODUCT`] the rest of the slots are `x`.
+fn day13(rng: &mut StdRng, size: usize) -> Result<String> {
+    ensure!(size >= 1, "Day 13 needs at least 1 bus slot");
+    let mut primes: Vec<i64> = (11..1000)
+        .filter(|&n| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect();
+    primes.shuffle(rng);
+    let mut product: i64 = 1;
+    let slots: Vec<String> = (0..size)
+        .map(|slot| match primes.last() {
+            Some(&bus)
+                if (slot == 0 || rng.gen_bool(0.2))
+                    && product
+                        .checked_mul(bus)
+                        .is_some_and(|p| p <= MAX_BUS_PRODUCT) =>
+            {
+                product *= bus;
+                primes.pop();
+                bus.to_string()
+            }
+            _ => "x".to_owned(),
+        })
+        .collect();
+    Ok(format!(
+        "{}\n{}\n",
+        rng.gen_range(100_000..=1_000_000),
+        slots.join(",")
+    ))
+}
+
+/// Masks have at most 9 floating bits, like the real inputs, so part 2 writes to at most 512
+/// addresses at a time.
+fn day14(rng: &mut StdRng, size: usize) -> Result<String> {
+    let mut program = Vec::with_capacity(size);
+    while program.len() < size {
+        let mut mask: Vec<char> = (0..36)
+            .map(|_| if rng.gen_bool(0.5) { '1' } else { '0' })
+            .collect();
+        let floating = rng.gen_range(0..=9);
+        for bit in index::sample(rng, 36, floating) {
+            mask[bit] = 'X';
+        }
+        program.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+        for _ in 0..rng.gen_range(1..=7).min(size - program.len()) {
+            program.push(format!(
+                "mem[{}] = {}",
+                rng.gen_range(0..65536),
+                rng.gen_range(0..1u64 << 36)
+            ));
+        }
+    }
+    Ok(lines(&program))
+}
+
+fn day15(rng: &mut StdRng, size: usize) -> Result<String> {
+    ensure!(size >= 1, "Day 15 needs at least 1 starting number");
+    let numbers: Vec<String> = index::sample(rng, (size * 3).max(20), size)
+        .into_iter()
+        .map(|number| number.to_string())
+        .collect();
+    Ok(numbers.join(",") + "\n")
+}
+
+/// Gives each field a rank and splits the valid values into a band per rank, plus a shared band
+/// every field allows. A field allows the bands up to its rank, and a column holds values from
+/// the bands up to its field's rank, always including one from that rank's band on your ticket.
+/// Only fields of the column's rank or higher allow all of it, so matching fields from the lowest
+/// rank up finds exactly one column for each.
+fn day16(rng: &mut StdRng, size: usize) -> Result<String> {
+    const FIELDS: [&str; 20] = [
+        "departure location",
+        "departure station",
+        "departure platform",
+        "departure track",
+        "departure date",
+        "departure time",
+        "arrival location",
+        "arrival station",
+        "arrival platform",
+        "arrival track",
+        "class",
+        "duration",
+        "price",
+        "route",
+        "row",
+        "seat",
+        "train",
+        "type",
+        "wagon",
+        "zone",
+    ];
+    // The shared band and then one for each rank, each with a gap before the next.
+    let mut bands = Vec::new();
+    let mut start = rng.gen_range(25..=50);
+    for _ in 0..=FIELDS.len() {
+        let end = start + rng.gen_range(20..=40);
+        bands.push((start, end));
+        start = end + 1 + rng.gen_range(1..=4);
+    }
+    let invalid_values = bands[bands.len() - 1].1 + 1..=999;
+    let (shared, ranked) = bands.split_first().expect("non-empty");
+
+    let mut ranks: Vec<usize> = (0..FIELDS.len()).collect();
+    ranks.shuffle(rng);
+    let mut rules: Vec<String> = FIELDS
+        .iter()
+        .zip(&ranks)
+        .map(|(field, &rank)| {
+            let split = rng.gen_range(0..=rank);
+            let first_end = if split == 0 {
+                shared.1
+            } else {
+                ranked[split - 1].1
+            };
+            format!(
+                "{}: {}-{} or {}-{}",
+                field, shared.0, first_end, ranked[split].0, ranked[rank].1
+            )
+        })
+        .collect();
+    rules.shuffle(rng);
+
+    let mut column_ranks = ranks;
+    column_ranks.shuffle(rng);
+    let random_ticket = |rng: &mut StdRng, top_band: bool| -> Vec<usize> {
+        column_ranks
+            .iter()
+            .map(|&rank| {
+                let (start, end) = if top_band {
+                    ranked[rank]
+                } else {
+                    bands[rng.gen_range(0..=rank + 1)]
+                };
+                rng.gen_range(start..=end)
+            })
+            .collect()
+    };
+    let format_ticket = |ticket: &[usize]| {
+        ticket
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+    let your_ticket = random_ticket(rng, true);
+    let nearby_tickets: Vec<String> = (0..size)
+        .map(|_| {
+            let mut ticket = random_ticket(rng, false);
+            if rng.gen_bool(0.25) {
+                let position = rng.gen_range(0..ticket.len());
+                ticket[position] = rng.gen_range(invalid_values.clone());
+            }
+            format_ticket(&ticket)
+        })
+        .collect();
+    Ok(format!(
+        "{}\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        lines(&rules),
+        format_ticket(&your_ticket),
+        lines(&nearby_tickets)
+    ))
+}
+
+fn day17(rng: &mut StdRng, size: usize) -> Result<String> {
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.gen_bool(0.45) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    Ok(lines(&rows))
+}
+
+/// The largest value any one expression may have under either part's rules, so the sum of
+/// millions of them still fits in an `i64`.
+const MAX_EXPRESSION_VALUE: u64 = 1_000_000_000_000;
+
+/// An arithmetic expression: its terms and the operators between them.
+struct Expression {
+    terms: Vec<Term>,
+    operators: Vec<char>,
+}
+
+enum Term {
+    Number(u64),
+    Parenthesized(Expression),
+}
+
+impl Expression {
+    fn random(rng: &mut StdRng, depth: usize) -> Self {
+        let count = if depth == 0 {
+            rng.gen_range(2..=7)
+        } else {
+            rng.gen_range(2..=4)
+        };
+        let terms = (0..count)
+            .map(|_| {
+                if depth < 3 && rng.gen_bool(0.25) {
+                    Term::Parenthesized(Expression::random(rng, depth + 1))
+                } else {
+                    Term::Number(rng.gen_range(1..=9))
+                }
+            })
+            .collect();
+        let operators = (1..count)
+            .map(|_| if rng.gen_bool(0.5) { '+' } else { '*' })
+            .collect();
+        Self { terms, operators }
+    }
+
+    /// Evaluates the expression left to right, or with addition before multiplication when
+    /// `addition_first`.
+    fn evaluate(&self, addition_first: bool) -> Option<u64> {
+        let values = self
+            .terms
+            .iter()
+            .map(|term| match term {
+                Term::Number(number) => Some(*number),
+                Term::Parenthesized(expression) => expression.evaluate(addition_first),
+            })
+            .collect::<Option<Vec<u64>>>()?;
+        if addition_first {
+            let mut products = vec![values[0]];
+            for (operator, &value) in self.operators.iter().zip(&values[1..]) {
+                match operator {
+                    '+' => {
+                        let last = products.last_mut().expect("non-empty");
+                        *last = last.checked_add(value)?;
+                    }
+                    _ => products.push(value),
+                }
+            }
+            products
+                .into_iter()
+                .try_fold(1u64, |product, value| product.checked_mul(value))
+        } else {
+            self.operators.iter().zip(&values[1..]).try_fold(
+                values[0],
+                |total, (operator, &value)| match operator {
+                    '+' => total.checked_add(value),
+                    _ => total.checked_mul(value),
+                },
+            )
+        }
+    }
+}
+
+impl std::fmt::Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (index, term) in self.terms.iter().enumerate() {
+            if index > 0 {
+                write!(f, " {} ", self.operators[index - 1])?;
+            }
+            match term {
+                Term::Number(number) => write!(f, "{}", number)?,
+                Term::Parenthesized(expression) => write!(f, "({})", expression)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn day18(rng: &mut StdRng, size: usize) -> Result<String> {
+    let fits = |value: Option<u64>| value.is_some_and(|value| value <= MAX_EXPRESSION_VALUE);
+    let expressions: Vec<Expression> = (0..size)
+        .map(|_| loop {
+            let expression = Expression::random(rng, 0);
+            if fits(expression.evaluate(false)) && fits(expression.evaluate(true)) {
+                break expression;
+            }
+        })
+        .collect();
+    Ok(lines(&expressions))
+}
+
+/// Rule 0 is `8 11` with `8: 42` and `11: 42 31` like the real inputs, so that part 2's looping
+/// rules apply. Rules 42 and 31 are built up from `"a"` and `"b"` in pairs of rules that match
+/// the same length of message and between them match every message of that length exactly once,
+/// so a message splits into runs of 42 and 31 in only one way.
+fn day19(rng: &mut StdRng, size: usize) -> Result<String> {
+    use day19::Rule;
+
+    const LEVELS: usize = 3;
+    const PAIRS_PER_LEVEL: usize = 4;
+    let reserved = [0, 8, 11, 31, 42];
+    let mut numbers: Vec<usize> = (1..=2 + LEVELS * PAIRS_PER_LEVEL * 2 + reserved.len())
+        .filter(|number| !reserved.contains(number))
+        .collect();
+    numbers.shuffle(rng);
+    let mut rules: HashMap<usize, Rule> = HashMap::new();
+    let mut add_rule = |number: Option<usize>, rule: Rule| {
+        let number = number.unwrap_or_else(|| numbers.pop().expect("enough rule numbers"));
+        rules.insert(number, rule);
+        number
+    };
+
+    let a = add_rule(None, Rule::Char('a'));
+    let b = add_rule(None, Rule::Char('b'));
+    let mut pairs = vec![(a, b)];
+    for level in 1..=LEVELS {
+        let count = if level == LEVELS { 1 } else { PAIRS_PER_LEVEL };
+        pairs = (0..count)
+            .map(|_| {
+                let &(x1, y1) = pairs.choose(rng).expect("non-empty");
+                let &(x2, y2) = pairs.choose(rng).expect("non-empty");
+                let mut same = (vec![x1, x2], vec![y1, y2]);
+                let mut different = (vec![x1, y2], vec![y1, x2]);
+                if rng.gen_bool(0.5) {
+                    same = (same.1, same.0);
+                }
+                if rng.gen_bool(0.5) {
+                    different = (different.1, different.0);
+                }
+                if rng.gen_bool(0.5) {
+                    std::mem::swap(&mut same, &mut different);
+                }
+                let (x, y) = if level == LEVELS {
+                    (Some(42), Some(31))
+                } else {
+                    (None, None)
+                };
+                (
+                    add_rule(x, Rule::Or(same)),
+                    add_rule(y, Rule::Or(different)),
+                )
+            })
+            .collect();
+    }
+    add_rule(Some(0), Rule::Seq(vec![8, 11]));
+    add_rule(Some(8), Rule::Seq(vec![42]));
+    add_rule(Some(11), Rule::Seq(vec![42, 31]));
+
+    fn sample(rng: &mut StdRng, rules: &HashMap<usize, Rule>, number: usize, message: &mut String) {
+        let seq = match &rules[&number] {
+            Rule::Char(c) => {
+                message.push(*c);
+                return;
+            }
+            Rule::Seq(seq) => seq,
+            Rule::Or((left, right)) => {
+                if rng.gen_bool(0.5) {
+                    left
+                } else {
+                    right
+                }
+            }
+        };
+        for &number in seq {
+            sample(rng, rules, number, message);
+        }
+    }
+    let messages: Vec<String> = (0..size)
+        .map(|_| {
+            let mut message = String::new();
+            let (forty_twos, thirty_ones) = match rng.gen_range(0..4) {
+                // Matches rule 0 as given.
+                0 => (2, 1),
+                // Only matches once rules 8 and 11 loop.
+                1 => {
+                    let thirty_ones = rng.gen_range(1..=3);
+                    (thirty_ones + rng.gen_range(1..=3), thirty_ones)
+                }
+                // Too many 31s to ever match.
+                2 => {
+                    let forty_twos = rng.gen_range(1..=3);
+                    (forty_twos, forty_twos + rng.gen_range(0..=2))
+                }
+                _ => {
+                    let length = rng.gen_range(1..=40);
+                    return (0..length)
+                        .map(|_| if rng.gen_bool(0.5) { 'a' } else { 'b' })
+                        .collect();
+                }
+            };
+            for _ in 0..forty_twos {
+                sample(rng, &rules, 42, &mut message);
+            }
+            for _ in 0..thirty_ones {
+                sample(rng, &rules, 31, &mut message);
+            }
+            message
+        })
+        .collect();
+    let mut rules: Vec<String> = rules
+        .iter()
+        .map(|(number, rule)| format!("{}: {}", number, rule))
+        .collect();
+    rules.shuffle(rng);
+    Ok(format!("{}\n{}", lines(&rules), lines(&messages)))
+}
+
+/// Draws an image with sea monsters in it and cuts it into tiles, giving every shared edge its
+/// own pattern so the tiles only fit together one way. Edges are 10 pixels and patterns come in
+/// pairs with their reverse, so there are only enough different patterns for 12 tiles per side.
+fn day20(rng: &mut StdRng, size: usize) -> Result<String> {
+    use day20::{orientations, Pixels, Tile, SEA_MONSTER};
+
+    ensure!(
+        (3..=12).contains(&size),
+        "Day 20 needs 3 to 12 tiles per side"
+    );
+    // The pixels at the corners where tiles meet, which the edges of all four tiles share.
+    let corners: Vec<Vec<bool>> = (0..=size)
+        .map(|_| (0..=size).map(|_| rng.gen_bool(0.5)).collect())
+        .collect();
+    let mut patterns = HashSet::new();
+    let mut edge = |rng: &mut StdRng, first: bool, last: bool| -> Result<Vec<bool>> {
+        for _ in 0..10_000 {
+            let mut pixels = vec![first];
+            pixels.extend((0..8).map(|_| rng.gen_bool(0.5)));
+            pixels.push(last);
+            let reversed: Vec<bool> = pixels.iter().rev().copied().collect();
+            if pixels != reversed && patterns.insert(pixels.clone().min(reversed)) {
+                return Ok(pixels);
+            }
+        }
+        bail!("Ran out of different edges for the tiles")
+    };
+    // The top edges of each row of tiles and the one below the last row, left to right.
+    let mut horizontal = Vec::new();
+    for row in &corners {
+        let edges = (0..size)
+            .map(|x| edge(rng, row[x], row[x + 1]))
+            .collect::<Result<Vec<_>>>()?;
+        horizontal.push(edges);
+    }
+    // The left edges of each column of tiles and the one right of the last column, top to bottom.
+    let mut vertical = Vec::new();
+    for y in 0..size {
+        let edges = (0..=size)
+            .map(|x| edge(rng, corners[y][x], corners[y + 1][x]))
+            .collect::<Result<Vec<_>>>()?;
+        vertical.push(edges);
+    }
+
+    let image_size = size * 8;
+    let mut image: Pixels = (0..image_size)
+        .map(|_| (0..image_size).map(|_| rng.gen_bool(0.3)).collect())
+        .collect();
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    let mut monster_pixels = HashSet::new();
+    for _ in 0..image_size * image_size / 100 {
+        let x = rng.gen_range(0..=image_size - SEA_MONSTER[0].len());
+        let y = rng.gen_range(0..=image_size - SEA_MONSTER.len());
+        if monster
+            .iter()
+            .all(|&(dx, dy)| !monster_pixels.contains(&(x + dx, y + dy)))
+        {
+            for &(dx, dy) in &monster {
+                monster_pixels.insert((x + dx, y + dy));
+                image[y + dy][x + dx] = true;
+            }
+        }
+    }
+
+    let ids = index::sample(rng, 9000, size * size);
+    let mut tiles: Vec<Tile> = (0..size * size)
+        .map(|index| {
+            let (x, y) = (index % size, index / size);
+            let mut pixels = vec![vec![false; 10]; 10];
+            for i in 0..10 {
+                pixels[0][i] = horizontal[y][x][i];
+                pixels[9][i] = horizontal[y + 1][x][i];
+                pixels[i][0] = vertical[y][x][i];
+                pixels[i][9] = vertical[y][x + 1][i];
+            }
+            for dy in 0..8 {
+                for dx in 0..8 {
+                    pixels[dy + 1][dx + 1] = image[y * 8 + dy][x * 8 + dx];
+                }
+            }
+            Tile {
+                id: 1000 + ids.index(index) as u64,
+                pixels: orientations(&pixels)
+                    .choose(rng)
+                    .expect("non-empty")
+                    .clone(),
+            }
+        })
+        .collect();
+    tiles.shuffle(rng);
+    Ok(tiles
+        .iter()
+        .map(|tile| tile.to_string())
+        .collect::<Vec<String>>()
+        .join("\n"))
+}
+
+/// Each allergen is in one ingredient, which is in every food listing that allergen. Inputs where
+/// the allergens can't all be worked out by elimination are thrown away and generated again.
+fn day21(rng: &mut StdRng, size: usize) -> Result<String> {
+    const ALLERGENS: [&str; 9] = [
+        "dairy",
+        "eggs",
+        "fish",
+        "nuts",
+        "peanuts",
+        "sesame",
+        "shellfish",
+        "soy",
+        "wheat",
+    ];
+    ensure!(size >= 1, "Day 21 needs at least 1 food");
+    for _ in 0..1000 {
+        let mut allergens: Vec<&str> = ALLERGENS
+            .choose_multiple(rng, size.min(8))
+            .copied()
+            .collect();
+        allergens.sort_unstable();
+        let mut taken = HashSet::new();
+        let dangerous = unique_words(rng, allergens.len(), &mut taken);
+        let safe = unique_words(rng, (size * 5).clamp(20, 200), &mut taken);
+
+        let mut candidates: Vec<Option<HashSet<&str>>> = vec![None; allergens.len()];
+        let foods: Vec<String> = (0..size)
+            .map(|food| {
+                let count = rng.gen_range(1..=3.min(allergens.len()));
+                let mut listed = index::sample(rng, allergens.len(), count).into_vec();
+                // Make sure every allergen is listed somewhere.
+                if food < allergens.len() && !listed.contains(&food) {
+                    listed[0] = food;
+                }
+                listed.sort_unstable();
+                let mut ingredients: Vec<&str> = dangerous
+                    .iter()
+                    .enumerate()
+                    .filter(|&(allergen, _)| listed.contains(&allergen) || rng.gen_bool(0.3))
+                    .map(|(_, ingredient)| ingredient.as_str())
+                    .collect();
+                ingredients.extend(
+                    safe.iter()
+                        .filter(|_| rng.gen_bool(0.3))
+                        .map(|ingredient| ingredient.as_str()),
+                );
+                let in_food: HashSet<&str> = ingredients.iter().copied().collect();
+                for &allergen in &listed {
+                    candidates[allergen]
+                        .get_or_insert_with(|| in_food.clone())
+                        .retain(|ingredient| in_food.contains(ingredient));
+                }
+                ingredients.shuffle(rng);
+                let listed: Vec<&str> =
+                    listed.iter().map(|&allergen| allergens[allergen]).collect();
+                format!("{} (contains {})", ingredients.join(" "), listed.join(", "))
+            })
+            .collect();
+
+        let mut candidates: Vec<HashSet<&str>> = candidates.into_iter().flatten().collect();
+        let mut found = 0;
+        while let Some(ingredient) = candidates
+            .iter()
+            .find(|candidates| candidates.len() == 1)
+            .and_then(|candidates| candidates.iter().next().copied())
+        {
+            for candidates in candidates.iter_mut() {
+                candidates.remove(ingredient);
+            }
+            found += 1;
+        }
+        if found == allergens.len() {
+            return Ok(lines(&foods));
+        }
+    }
+    bail!(
+        "Couldn't work out every allergen in {} foods, try more of them",
+        size
+    )
+}
+
+/// Deals the cards out at random, dealing again if the game of part 1 would never end.
+fn day22(rng: &mut StdRng, size: usize) -> Result<String> {
+    use day22::{Deck, Game};
+
+    ensure!(size >= 1, "Day 22 needs at least 1 card per player");
+    for _ in 0..100 {
+        let mut cards: Vec<usize> = (1..=size * 2).collect();
+        cards.shuffle(rng);
+        let (first, second) = cards.split_at(size);
+        let decks = [
+            first.iter().copied().collect::<Deck>(),
+            second.iter().copied().collect::<Deck>(),
+        ];
+        if Game::new(&decks, false).play().is_ok() {
+            return Ok(format!(
+                "Player 1:\n{}\nPlayer 2:\n{}",
+                lines(first),
+                lines(second)
+            ));
+        }
+    }
+    bail!("Couldn't deal {} cards each for a game that ends", size)
+}
+
+/// The cups are labelled with single digits, so there can only be up to 9 of them.
+fn day23(rng: &mut StdRng, size: usize) -> Result<String> {
+    ensure!((5..=9).contains(&size), "Day 23 needs 5 to 9 cups");
+    let mut cups: Vec<usize> = (1..=size).collect();
+    cups.shuffle(rng);
+    let mut input: String = cups.iter().map(|cup| cup.to_string()).collect();
+    input.push('\n');
+    Ok(input)
+}
+
+fn day24(rng: &mut StdRng, size: usize) -> Result<String> {
+    let paths: Vec<String> = (0..size)
+        .map(|_| {
+            (0..rng.gen_range(10..=25))
+                .map(|_| {
+                    *["e", "se", "sw", "w", "nw", "ne"]
+                        .choose(rng)
+                        .expect("non-empty")
+                })
+                .collect()
+        })
+        .collect();
+    Ok(lines(&paths))
+}
+
+/// The input is always the card's and the door's public keys, transformed from random loop sizes.
+fn day25(rng: &mut StdRng, size: usize) -> Result<String> {
+    use day25::{transform, MODULUS, SUBJECT_NUMBER};
+
+    ensure!(size == 2, "Day 25's input is always 2 public keys");
+    let mut keys = String::new();
+    for _ in 0..size {
+        let loop_size = rng.gen_range(1..MODULUS as u64 - 1);
+        writeln!(keys, "{}", transform(SUBJECT_NUMBER, loop_size))?;
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::Solution;
+
+    use crate::days;
+
+    #[test]
+    fn solves_generated_inputs() {
+        for generator in all() {
+            let day = days::find(generator.day).unwrap();
+            let size = match generator.day {
+                20 => 3,
+                21 => 30,
+                22 => 10,
+                5 | 15 | 17 | 23 | 25 => generator.default_size,
+                _ => generator.default_size.min(50),
+            };
+            // Part 2 of days 15 and 23 takes millions of steps whatever the input, and day 25 has
+            // no part 2.
+            let parts: &[u8] = match generator.day {
+                15 | 23 | 25 => &[1],
+                _ => &[1, 2],
+            };
+            let input = generator.generate(size, 1).unwrap();
+            let report = (day.solve)(&input, parts, &Default::default());
+            assert!(
+                report.parse_error.is_none(),
+                "day {} failed to parse: {:?}",
+                generator.day,
+                report.parse_error
+            );
+            for part in report.parts {
+                assert!(
+                    part.answer.is_ok(),
+                    "day {} part {} failed: {:?}",
+                    generator.day,
+                    part.part,
+                    part.answer
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn generates_the_same_input_for_a_seed() {
+        let generator = find(16).unwrap();
+        assert_eq!(
+            generator.generate(20, 7).unwrap(),
+            generator.generate(20, 7).unwrap()
+        );
+        assert_ne!(
+            generator.generate(20, 7).unwrap(),
+            generator.generate(20, 8).unwrap()
+        );
+    }
+
+    #[test]
+    fn generates_acyclic_bag_rules() {
+        fn visit<'a>(
+            contents: &'a day07::ContainerToInnerBags,
+            color: &'a str,
+            path: &mut Vec<&'a str>,
+            done: &mut HashSet<&'a str>,
+        ) {
+            assert!(!path.contains(&color), "{} contains itself", color);
+            if done.contains(color) {
+                return;
+            }
+            path.push(color);
+            for (_, inner) in contents.get(color).into_iter().flatten() {
+                visit(contents, inner, path, done);
+            }
+            path.pop();
+            done.insert(color);
+        }
+        for seed in 0..5 {
+            let input = find(7).unwrap().generate(2000, seed).unwrap();
+            assert_eq!(input.lines().count(), 2000);
+            let rules = day07::Day07::parse(&input).unwrap();
+            let mut done = HashSet::new();
+            for color in rules.contents.keys() {
+                visit(&rules.contents, color, &mut Vec::new(), &mut done);
+            }
+            assert!(day07::solve_part1(&rules).unwrap() > 0);
+            assert!(day07::solve_part2(&rules).unwrap() > 0);
+        }
+    }
+
+    #[test]
+    fn generates_bounded_xmas_numbers() {
+        for (size, seed) in [(26, 0), (1600, 1), (20_000, 2)] {
+            let input = find(9).unwrap().generate(size, seed).unwrap();
+            let nums = day09::Day09::parse(&input).unwrap();
+            assert_eq!(nums.len(), size);
+            let invalid_num = day09::solve_part1(&nums, day09::PREAMBLE).unwrap();
+            assert!(day09::solve_part2(&nums, invalid_num).is_ok());
+        }
+    }
+
+    #[test]
+    fn generates_programs_fixed_by_one_flip() {
+        for (size, seed) in [(1, 0), (2, 0), (10, 1), (650, 2), (5000, 3)] {
+            let input = find(8).unwrap().generate(size, seed).unwrap();
+            let program = day08::Day08::parse(&input).unwrap();
+            assert_eq!(program.len(), size);
+            assert!(day08::find_infinite_loop(&program).is_ok());
+            let fixes = (0..size as i32)
+                .filter(|&flip| day08::find_terminated_value(&program, flip).is_some())
+                .count();
+            assert_eq!(fixes, 1, "program {} from seed {}", size, seed);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use std::io::{self, IsTerminal};
+
+use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::EnvFilter;
+
+/// How to write log lines to stderr.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum LogFormat {
+    Text,
+    /// One JSON object per event, with the spans it happened in.
+    Json,
+}
+
+/// The most detailed level to log, going from warnings only to every trace with each `-v`.
+fn level(verbose: u8) -> &'static str {
+    match verbose {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    }
+}
+
+/// A subscriber writing each event at the level `verbose` asks for, or that `RUST_LOG` asks for
+/// when it is set, to `writer`. Text is only colored when `ansi` is set.
+pub fn subscriber<W>(
+    verbose: u8,
+    format: LogFormat,
+    writer: W,
+    ansi: bool,
+) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(level(verbose)));
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(writer)
+        .with_ansi(ansi);
+    match format {
+        LogFormat::Text => Box::new(builder.finish()),
+        LogFormat::Json => Box::new(builder.json().with_span_list(true).finish()),
+    }
+}
+
+/// Logs to stderr for the rest of the run, in color when it is a terminal.
+pub fn init(verbose: u8, format: LogFormat) -> Result<()> {
+    let ansi = io::stderr().is_terminal();
+    tracing::subscriber::set_global_default(subscriber(verbose, format, io::stderr, ansi))
+        .map_err(|error| anyhow!("Failed to set up logging: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    use aoc_common::{solve, NoParams};
+    use serde_json::Value;
+
+    /// Collects everything written to it, so the logs can be checked.
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'writer> MakeWriter<'writer> for Capture {
+        type Writer = Capture;
+
+        fn make_writer(&'writer self) -> Capture {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn logs_spans_as_json() {
+        let capture = Capture::default();
+        let subscriber = subscriber(1, LogFormat::Json, capture.clone(), false);
+        tracing::subscriber::with_default(subscriber, || {
+            solve::<day13::Day13>("939\n7,13,x,x,59,x,31,19\n", &[1, 2], &NoParams {});
+        });
+        let logs = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        let events: Vec<Value> = logs
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let spans: Vec<&str> = events
+            .iter()
+            .map(|event| event["span"]["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(spans, vec!["parse", "part1", "part2"]);
+        assert!(events.iter().all(|event| event["spans"][0]["day"] == 13));
+        assert_eq!(events[1]["fields"]["answer"], Value::from("295"));
+        assert_eq!(events[2]["fields"]["answer"], Value::from("1068781"));
+    }
+
+    #[test]
+    fn logs_debug_events_with_two_flags() {
+        let capture = Capture::default();
+        let subscriber = subscriber(2, LogFormat::Text, capture.clone(), false);
+        tracing::subscriber::with_default(subscriber, || {
+            solve::<day13::Day13>("939\n7,13,x,x,59,x,31,19\n", &[1], &NoParams {});
+        });
+        let logs = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("looking for the first bus to leave earliest=939"));
+    }
+
+    #[test]
+    fn leaves_failures_to_the_report_by_default() {
+        let capture = Capture::default();
+        let subscriber = subscriber(0, LogFormat::Text, capture.clone(), false);
+        tracing::subscriber::with_default(subscriber, || {
+            solve::<day13::Day13>("939\n", &[1], &NoParams {});
+            solve::<day13::Day13>("939\nx\n", &[1], &NoParams {});
+        });
+        assert!(capture.0.lock().unwrap().is_empty());
+    }
+}
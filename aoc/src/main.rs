@@ -0,0 +1,445 @@
+use std::cmp::Reverse;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Instant;
+
+use anyhow::{anyhow, bail, Error, Result};
+use aoc_common::{InputArgs, InputSource};
+use clap::{Parser, Subcommand};
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use toml::Table;
+
+mod client;
+mod compare;
+mod config;
+mod days;
+mod export;
+mod generate;
+mod logging;
+mod mock_server;
+mod params;
+mod reference;
+mod repl;
+mod run;
+mod scaffold;
+mod submit;
+mod verify;
+
+use client::{download_input, input_path, Client};
+use compare::compare_day;
+use config::{Config, CONFIG_FILE};
+use export::Format;
+use logging::LogFormat;
+use mock_server::MockServer;
+use params::Override;
+use run::{format_summary, print_day, run_day};
+use submit::{submit, History, Outcome, Verdict};
+use verify::{part_answer, verify_day};
+
+/// Runs the Advent of Code 2020 solutions.
+#[derive(Parser)]
+struct Cli {
+    /// Directory containing the `dayNN` crates and their inputs.
+    #[arg(long, global = true, default_value = concat!(env!("CARGO_MANIFEST_DIR"), "/.."))]
+    root: PathBuf,
+
+    /// Logs what each day is doing to stderr: `-v` for each step's result and time, `-vv` for the
+    /// values the days work out along the way and `-vvv` for everything. `RUST_LOG` overrides it.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// How to write the logs.
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs one day, or all of them at once, and prints a summary of the parse and solve times,
+    /// slowest day first.
+    Run {
+        /// The day to run, or `all`.
+        day: DaySelection,
+
+        /// Only run this part (1 or 2).
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Replaces the day's `input/input.txt`. Only allowed when running a single day.
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// How to print the results.
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+
+        /// Sets one of a day's parameters for this run, overriding `aoc.toml`, like
+        /// `--param day15.part2_turns=1000`. The day can be left out when only running one.
+        #[arg(long = "param", value_name = "[DAYNN.]NAME=VALUE")]
+        params: Vec<Override>,
+
+        /// How many days to run at once. Defaults to one per CPU. With `--jobs 1` the days run one
+        /// after another, so their timings don't compete for CPUs.
+        #[arg(long, value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+    },
+    /// Checks one day, or all of them, against the answers recorded in each day's `answers.toml`.
+    Verify {
+        /// The day to verify, or `all`.
+        day: DaySelection,
+    },
+    /// Prints a random input for a day, to stress test or benchmark its solution at any size.
+    Generate {
+        /// The day to generate an input for.
+        day: u8,
+
+        /// How large to make the input, in a unit that depends on the day. Defaults to about the
+        /// size of the real input.
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Seeds the random input, so the same input can be generated again. Without one, a random
+        /// seed is used and printed to stderr.
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Compares one day, or all of them, against simple reference solutions on small generated
+    /// inputs.
+    Compare {
+        /// The day to compare, or `all`.
+        day: DaySelection,
+
+        /// How many inputs to generate for each day.
+        #[arg(long, default_value_t = 10)]
+        runs: u64,
+
+        /// The seed of the first input. Each later input uses the next seed.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How large to make the inputs. Defaults to a size each day's reference solves quickly.
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Parses a day's input and reads commands from stdin to print views of the parsed input and
+    /// solve its parts with different parameters. Type `help` to list the commands.
+    Repl {
+        day: u8,
+
+        /// Replaces the day's `input/input.txt`.
+        #[command(flatten)]
+        input: InputArgs,
+
+        /// Sets one of the day's parameters for the session, overriding `aoc.toml`, like
+        /// `--param preamble=5`.
+        #[arg(long = "param", value_name = "NAME=VALUE")]
+        params: Vec<Override>,
+    },
+    /// Downloads the input for one day, or all of them, into `input/input.txt` with the session
+    /// token from `AOC_SESSION` or the session file. Inputs already there are kept.
+    Download {
+        /// The day to download, or `all`.
+        day: DaySelection,
+
+        /// Downloads inputs again even if they are already there.
+        #[arg(long)]
+        force: bool,
+
+        /// Downloads from this site instead of the one in `aoc.toml`, like the mock server.
+        #[arg(long)]
+        url: Option<String>,
+    },
+    /// Solves a part of a day with its input and submits the answer, unless an earlier answer shows
+    /// it can't be right or the site won't check another answer yet.
+    Submit {
+        day: u8,
+
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Submits this answer instead of solving the part.
+        #[arg(long)]
+        answer: Option<String>,
+
+        /// Submits to this site instead of the one in `aoc.toml`, like the mock server.
+        #[arg(long)]
+        url: Option<String>,
+    },
+    /// Serves each day's example input as its puzzle input on localhost, and checks answers against
+    /// the example's, as a stand-in for the puzzle site.
+    Serve {
+        #[arg(long, default_value_t = 8020)]
+        port: u16,
+
+        /// The session token to accept.
+        #[arg(long, default_value = "mock-session")]
+        session: String,
+    },
+    /// Creates the crate for a new day with stub solutions and registers it with the runner.
+    New {
+        /// The day to create.
+        day: u8,
+    },
+}
+
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+impl FromStr for DaySelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s == "all" {
+            Ok(DaySelection::All)
+        } else {
+            Ok(DaySelection::Day(s.parse().map_err(|_| {
+                anyhow!("Expected a day number or `all`, got: {}", s)
+            })?))
+        }
+    }
+}
+
+impl DaySelection {
+    fn days(self) -> Result<Vec<days::Day>> {
+        match self {
+            DaySelection::All => Ok(days::all()),
+            DaySelection::Day(number) => {
+                Ok(vec![days::find(number).ok_or_else(|| {
+                    anyhow!("Day {} has no solution", number)
+                })?])
+            }
+        }
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    logging::init(cli.verbose, cli.log_format)?;
+    let root = cli.root;
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            params: overrides,
+            jobs,
+        } => {
+            if matches!(day, DaySelection::All) && !input.is_empty() {
+                bail!("An input can only be given when running a single day");
+            }
+            let days = day.days()?;
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            let mut params = Config::load(root.join(CONFIG_FILE))?.params;
+            params.apply(&overrides, &days)?;
+            let pool = ThreadPoolBuilder::new()
+                .num_threads(jobs.map_or(0, usize::from))
+                .build()?;
+            let started = Instant::now();
+            let mut reports: Vec<_> = pool.install(|| {
+                days.par_iter()
+                    .map(|day| {
+                        let default_input = root.join(day.name()).join("input").join("input.txt");
+                        run_day(
+                            day,
+                            &input.source(default_input),
+                            &parts,
+                            &params.for_day(day),
+                        )
+                    })
+                    .collect()
+            });
+            let elapsed = started.elapsed();
+            match format {
+                Format::Text => {
+                    for report in &reports {
+                        print_day(report);
+                    }
+                    reports.sort_by_key(|report| Reverse(report.elapsed()));
+                    print!("{}", format_summary(&reports));
+                    if reports.len() > 1 {
+                        let threads = pool.current_num_threads();
+                        println!(
+                            "Ran {} days in {:?} on {} thread{}",
+                            reports.len(),
+                            elapsed,
+                            threads,
+                            if threads == 1 { "" } else { "s" }
+                        );
+                    }
+                }
+                Format::Json => println!("{}", export::to_json(&export::rows(&reports, &parts))?),
+                Format::Csv => print!("{}", export::to_csv(&export::rows(&reports, &parts))?),
+            }
+        }
+        Command::Verify { day } => {
+            let mut checks = Vec::new();
+            for day in day.days()? {
+                for check in verify_day(&day, &root.join(day.name()))? {
+                    println!("{}", check);
+                    checks.push(check);
+                }
+            }
+            let failed = checks.iter().filter(|check| !check.passed()).count();
+            println!();
+            println!(
+                "{} of {} checks passed",
+                checks.len() - failed,
+                checks.len()
+            );
+            if failed > 0 {
+                bail!("{} checks failed", failed);
+            }
+        }
+        Command::Generate { day, size, seed } => {
+            let generator =
+                generate::find(day).ok_or_else(|| anyhow!("Day {} has no generator", day))?;
+            let seed = seed.unwrap_or_else(|| {
+                let seed = rand::random();
+                eprintln!("Seed: {}", seed);
+                seed
+            });
+            let size = size.unwrap_or(generator.default_size);
+            eprintln!("Generating {} {} for day {}", size, generator.unit, day);
+            print!("{}", generator.generate(size, seed)?);
+        }
+        Command::Compare {
+            day,
+            runs,
+            seed,
+            size,
+        } => {
+            let mut comparisons = Vec::new();
+            for day in day.days()? {
+                let reference = reference::find(day.number)
+                    .ok_or_else(|| anyhow!("Day {} has no reference solution", day.number))?;
+                let generator = generate::find(day.number)
+                    .ok_or_else(|| anyhow!("Day {} has no generator", day.number))?;
+                let size = size.unwrap_or(reference.size);
+                for comparison in
+                    compare_day(&day, &reference, &generator, size, seed..seed + runs)?
+                {
+                    if !comparison.matched() {
+                        println!("{}", comparison);
+                    }
+                    comparisons.push(comparison);
+                }
+            }
+            let failed = comparisons
+                .iter()
+                .filter(|comparison| !comparison.matched())
+                .count();
+            if failed > 0 {
+                println!();
+            }
+            println!(
+                "{} of {} comparisons matched",
+                comparisons.len() - failed,
+                comparisons.len()
+            );
+            if failed > 0 {
+                bail!("{} comparisons didn't match", failed);
+            }
+        }
+        Command::Repl {
+            day,
+            input,
+            params: overrides,
+        } => {
+            let day = days::find(day).ok_or_else(|| anyhow!("Day {} has no solution", day))?;
+            let mut params = Config::load(root.join(CONFIG_FILE))?.params;
+            params.apply(&overrides, std::slice::from_ref(&day))?;
+            let source = input.source(input_path(&root, day.number));
+            if matches!(source, InputSource::Stdin) {
+                bail!("The REPL reads commands from stdin, so it can't read the input from it too");
+            }
+            let (mut session, parsed) = (day.repl)(&source.read()?, params.for_day(&day))?;
+            println!("{}", parsed);
+            let stdin = io::stdin();
+            let prompt = stdin.is_terminal();
+            if prompt {
+                println!("Type help to list the commands");
+            }
+            repl::run(session.as_mut(), stdin.lock(), io::stdout(), prompt)?;
+        }
+        Command::Download { day, force, url } => {
+            let mut config = Config::load(root.join(CONFIG_FILE))?.client;
+            if let Some(url) = url {
+                config.url = url;
+            }
+            let client = Client::from_config(&config, &root)?;
+            for day in day.days()? {
+                let (path, downloaded) = download_input(&client, &root, day.number, force)?;
+                let path = path.strip_prefix(&root).unwrap_or(&path);
+                if downloaded {
+                    println!("Downloaded {}", path.display());
+                } else {
+                    println!("Kept {}, pass --force to download it again", path.display());
+                }
+            }
+        }
+        Command::Submit {
+            day,
+            part,
+            answer,
+            url,
+        } => {
+            let mut config = Config::load(root.join(CONFIG_FILE))?.client;
+            if let Some(url) = url {
+                config.url = url;
+            }
+            let client = Client::from_config(&config, &root)?;
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let day =
+                        days::find(day).ok_or_else(|| anyhow!("Day {} has no solution", day))?;
+                    let source = InputSource::Path(input_path(&root, day.number));
+                    let report = run_day(&day, &source, &[part], &Table::new());
+                    part_answer(&report, part)?.to_string()
+                }
+            };
+            println!("Submitting {} for day {} part {}", answer, day, part);
+            let history_path = root.join(&config.history_file);
+            let mut history = History::load(&history_path)?;
+            let outcome = submit(&client, &mut history, day, part, &answer)?;
+            history.save(&history_path)?;
+            println!("{}", outcome);
+            if !matches!(
+                outcome,
+                Outcome::Answered {
+                    verdict: Verdict::Correct,
+                    ..
+                }
+            ) {
+                bail!("Day {} part {} wasn't solved", day, part);
+            }
+        }
+        Command::Serve { port, session } => {
+            let server = MockServer::start(&root, &session, port)?;
+            println!(
+                "Serving example inputs at {} to session {}",
+                server.url(),
+                session
+            );
+            server.wait();
+        }
+        Command::New { day } => {
+            for path in scaffold::new_day(&root, day)? {
+                println!("{}", path.display());
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,249 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Result};
+use tiny_http::{Method, Request, Response, Server};
+use tracing::info;
+
+use crate::client::YEAR;
+use crate::submit::format_wait;
+use crate::verify::{load_answers, ANSWERS_FILE};
+
+/// How long the site makes someone wait after a wrong answer before it checks another.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// The example inputs each day may have, in the order the mock server looks for them.
+const EXAMPLES: [&str; 2] = ["test.txt", "test1.txt"];
+
+/// A stand-in for the puzzle site on localhost, so downloading and submitting can be developed and
+/// tested offline. It serves each day's example input from `input/test.txt` (or `input/test1.txt`)
+/// as the puzzle input of whoever has its session token, and checks answers against the ones
+/// recorded for it in `answers.toml`, making them wait after a wrong answer as the site does.
+pub struct MockServer {
+    server: Arc<Server>,
+    url: String,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Serves the days in the workspace at `root` on `port`, or any free port if it is 0.
+    pub fn start(root: &Path, session: &str, port: u16) -> Result<Self> {
+        let server = Arc::new(
+            Server::http(("127.0.0.1", port))
+                .map_err(|error| anyhow!("Failed to start the mock server: {}", error))?,
+        );
+        let address = server
+            .server_addr()
+            .to_ip()
+            .ok_or_else(|| anyhow!("The mock server isn't listening on an IP address"))?;
+        let site = Site {
+            root: root.to_owned(),
+            session: session.to_owned(),
+            state: Mutex::default(),
+        };
+        let thread = {
+            let server = Arc::clone(&server);
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let (status, body) = site.respond(&mut request);
+                    info!(method = %request.method(), url = request.url(), status, "served");
+                    // The client hanging up early only matters to the client.
+                    let _ = request.respond(Response::from_string(body).with_status_code(status));
+                }
+            })
+        };
+        Ok(Self {
+            server,
+            url: format!("http://{}", address),
+            thread: Some(thread),
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Serves requests until the process is stopped.
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// What the mock server serves and who to.
+struct Site {
+    root: PathBuf,
+    session: String,
+    state: Mutex<State>,
+}
+
+/// What the mock server remembers between answers.
+#[derive(Default)]
+struct State {
+    solved: HashSet<(u8, u8)>,
+    next_answer: Option<Instant>,
+}
+
+impl Site {
+    fn respond(&self, request: &mut Request) -> (u16, String) {
+        let path = request.url().split('?').next().unwrap_or_default();
+        let (day, page) = match path
+            .strip_prefix(&format!("/{}/day/", YEAR))
+            .and_then(|path| path.split_once('/'))
+            .and_then(|(day, page)| Some((day.parse::<u8>().ok()?, page.to_owned())))
+        {
+            Some(route) => route,
+            None => return not_found(),
+        };
+        if session(request).as_deref() != Some(self.session.as_str()) {
+            return (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input."
+                    .to_string(),
+            );
+        }
+        match (request.method(), page.as_str()) {
+            (Method::Get, "input") => self
+                .example(day)
+                .map_or_else(not_found, |(_, input)| (200, input)),
+            (Method::Post, "answer") => {
+                let mut body = String::new();
+                if request.as_reader().read_to_string(&mut body).is_err() {
+                    return (400, "Expected a form".to_string());
+                }
+                let form = parse_form(&body);
+                let level = form
+                    .iter()
+                    .find(|(name, _)| name == "level")
+                    .and_then(|(_, level)| level.parse().ok());
+                let answer = form.iter().find(|(name, _)| name == "answer");
+                match (level, answer) {
+                    (Some(level), Some((_, answer))) => self.answer(day, level, answer),
+                    _ => (400, "Expected a level and an answer".to_string()),
+                }
+            }
+            _ => not_found(),
+        }
+    }
+
+    /// The example input served for `day` and its file name.
+    fn example(&self, day: u8) -> Option<(&'static str, String)> {
+        let dir = self.root.join(format!("day{:02}", day)).join("input");
+        EXAMPLES
+            .iter()
+            .find_map(|name| Some((*name, fs::read_to_string(dir.join(name)).ok()?)))
+    }
+
+    fn answer(&self, day: u8, level: u8, answer: &str) -> (u16, String) {
+        let expected = self.example(day).and_then(|(name, _)| {
+            load_answers(self.root.join(format!("day{:02}", day)).join(ANSWERS_FILE))
+                .ok()?
+                .into_iter()
+                .find(|input| input.path == Path::new("input").join(name))?
+                .parts
+                .into_iter()
+                .find(|(part, _)| *part == level)
+                .map(|(_, answer)| answer.to_string())
+        });
+        let expected = match expected {
+            Some(expected) => expected,
+            None => return not_found(),
+        };
+        let mut state = self.state.lock().unwrap_or_else(|error| error.into_inner());
+        let now = Instant::now();
+        let message = match state.next_answer {
+            Some(next) if next > now => format!(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have {} left to wait.",
+                format_wait(next - now)
+            ),
+            _ if state.solved.contains(&(day, level))
+                || (level == 2 && !state.solved.contains(&(day, 1))) =>
+            {
+                "You don't seem to be solving the right level.  Did you already complete it?"
+                    .to_string()
+            }
+            _ if answer == expected => {
+                state.solved.insert((day, level));
+                "That's the right answer!  You are one gold star closer to saving your vacation."
+                    .to_string()
+            }
+            _ => {
+                state.next_answer = Some(now + WRONG_ANSWER_WAIT);
+                let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+                    (Ok(answer), Ok(expected)) if answer > expected => "; your answer is too high",
+                    (Ok(answer), Ok(expected)) if answer < expected => "; your answer is too low",
+                    _ => "",
+                };
+                format!(
+                    "That's not the right answer{}.  Please wait one minute before trying again.",
+                    hint
+                )
+            }
+        };
+        (
+            200,
+            format!("<main><article><p>{}</p></article></main>", message),
+        )
+    }
+}
+
+fn not_found() -> (u16, String) {
+    (404, "404 Not Found".to_string())
+}
+
+/// The names and values in a URL encoded form.
+fn parse_form(body: &str) -> Vec<(String, String)> {
+    body.split('&')
+        .filter_map(|field| field.split_once('='))
+        .map(|(name, value)| (decode(name), decode(value)))
+        .collect()
+}
+
+fn decode(s: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut rest = s.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => match rest
+                .get(..2)
+                .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok())
+            {
+                Some(decoded) => {
+                    bytes.push(decoded);
+                    rest = &rest[2..];
+                }
+                None => bytes.push(byte),
+            },
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// The session token in a request's cookies.
+fn session(request: &Request) -> Option<String> {
+    request
+        .headers()
+        .iter()
+        .filter(|header| header.field.equiv("Cookie"))
+        .flat_map(|header| header.value.as_str().split(';'))
+        .find_map(|cookie| cookie.trim().strip_prefix("session="))
+        .map(str::to_owned)
+}
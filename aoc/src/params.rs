@@ -0,0 +1,201 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, bail, Error, Result};
+use toml::{Table, Value};
+
+use crate::days::{self, Day};
+
+/// The parameters to run each day with, in tables named after the days' crates, like `[day09]`.
+/// Days and parameters that aren't set keep the puzzle's own values.
+#[derive(Debug, Default)]
+pub struct Params(Table);
+
+impl Params {
+    pub fn from_table(table: Table) -> Result<Self> {
+        for (name, params) in &table {
+            let day = days::all()
+                .into_iter()
+                .find(|day| day.name() == *name)
+                .ok_or_else(|| anyhow!("There is no day named {}", name))?;
+            let params = params
+                .as_table()
+                .ok_or_else(|| anyhow!("Expected a table of parameters for {}", name))?;
+            (day.check_params)(params)?;
+        }
+        Ok(Self(table))
+    }
+
+    /// The parameters set for `day`.
+    pub fn for_day(&self, day: &Day) -> Table {
+        self.0
+            .get(&day.name())
+            .and_then(Value::as_table)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Sets each overridden parameter for one of the `days` being run, replacing any value from the
+    /// file. An override that doesn't name a day sets it for the only day being run.
+    pub fn apply(&mut self, overrides: &[Override], days: &[Day]) -> Result<()> {
+        for param in overrides {
+            let day = match (param.day, days) {
+                (Some(number), _) => days
+                    .iter()
+                    .find(|day| day.number == number)
+                    .ok_or_else(|| anyhow!("Day {} isn't being run", number))?,
+                (None, [day]) => day,
+                (None, _) => bail!(
+                    "Expected a day for {}, like day09.{}, when running more than one day",
+                    param.name,
+                    param.name
+                ),
+            };
+            self.0
+                .entry(day.name())
+                .or_insert_with(|| Value::Table(Table::new()))
+                .as_table_mut()
+                .expect("every day's parameters are checked to be a table")
+                .insert(param.name.clone(), param.value.clone());
+            (day.check_params)(&self.for_day(day))?;
+        }
+        Ok(())
+    }
+}
+
+/// A parameter set on the command line, like `day09.preamble=5`, or just `preamble=5` when only one
+/// day is run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Override {
+    pub day: Option<u8>,
+    pub name: String,
+    pub value: Value,
+}
+
+impl FromStr for Override {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (name, value) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Expected a parameter like preamble=5, got: {}", s))?;
+        let (day, name) = match name.split_once('.') {
+            Some((day, name)) => (
+                Some(
+                    day.strip_prefix("day")
+                        .and_then(|number| number.parse().ok())
+                        .ok_or_else(|| anyhow!("Expected a day like day09, got: {}", day))?,
+                ),
+                name,
+            ),
+            None => (None, name),
+        };
+        // Values are read as TOML, so `5` is a number, but anything that isn't valid TOML, like a
+        // bare word, is taken as a string.
+        let value = format!("value = {}", value)
+            .parse::<Table>()
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| Value::String(value.to_owned()));
+        Ok(Self {
+            day,
+            name: name.to_owned(),
+            value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::{read_input, Answer};
+
+    use std::path::Path;
+
+    use crate::verify::part_answer;
+
+    fn parse(s: &str) -> Result<Params> {
+        Params::from_table(toml::from_str(s)?)
+    }
+
+    #[test]
+    fn parses_params() {
+        let params = parse("[day09]\npreamble = 5\n").unwrap();
+        let day = days::find(9).unwrap();
+        assert_eq!(params.for_day(&day)["preamble"], Value::Integer(5));
+        assert!(params.for_day(&days::find(15).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_params() {
+        let error = |s: &str| format!("{:#}", parse(s).unwrap_err());
+        assert_eq!(error("[day26]\nturns = 5\n"), "There is no day named day26");
+        assert_eq!(
+            error("day09 = 5\n"),
+            "Expected a table of parameters for day09"
+        );
+        assert!(error("[day09]\npreambel = 5\n").starts_with(
+            "Invalid parameters for day 9: unknown field `preambel`, expected `preamble`"
+        ));
+        assert!(error("[day09]\npreamble = \"five\"\n").starts_with(
+            "Invalid parameters for day 9: invalid type: string \"five\", expected usize"
+        ));
+        assert!(error("[day02]\nlength = 5\n").starts_with(
+            "Invalid parameters for day 2: unknown field `length`, there are no fields"
+        ));
+    }
+
+    #[test]
+    fn parses_overrides() {
+        assert_eq!(
+            "day09.preamble=5".parse::<Override>().unwrap(),
+            Override {
+                day: Some(9),
+                name: "preamble".to_string(),
+                value: Value::Integer(5),
+            }
+        );
+        assert_eq!(
+            "name=abc".parse::<Override>().unwrap(),
+            Override {
+                day: None,
+                name: "name".to_string(),
+                value: Value::String("abc".to_string()),
+            }
+        );
+        assert!("preamble".parse::<Override>().is_err());
+        assert!("nine.preamble=5".parse::<Override>().is_err());
+    }
+
+    #[test]
+    fn applies_overrides() {
+        let mut params = parse("[day15]\npart1_turns = 10\npart2_turns = 20\n").unwrap();
+        let days = vec![days::find(15).unwrap()];
+        params
+            .apply(&["part2_turns=30".parse().unwrap()], &days)
+            .unwrap();
+        let table = params.for_day(&days[0]);
+        assert_eq!(table["part1_turns"], Value::Integer(10));
+        assert_eq!(table["part2_turns"], Value::Integer(30));
+
+        assert!(params
+            .apply(&["day09.preamble=5".parse().unwrap()], &days)
+            .is_err());
+        assert!(params
+            .apply(&["part2_turns=-1".parse().unwrap()], &days)
+            .is_err());
+        assert!(params
+            .apply(&["cycles=5".parse().unwrap()], &days::all())
+            .is_err());
+    }
+
+    #[test]
+    fn solves_with_params() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let day = days::find(9).unwrap();
+        let input = read_input(root.join("day09/input/test.txt")).unwrap();
+        let params = parse("[day09]\npreamble = 5\n").unwrap();
+        let report = (day.solve)(&input, &[1], &params.for_day(&day));
+        assert_eq!(part_answer(&report, 1).unwrap(), Answer::Integer(127));
+    }
+}
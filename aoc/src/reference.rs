@@ -0,0 +1,140 @@
+//! Simple, slow solutions to every day, written independently of the real ones so the two can be
+//! compared on generated inputs. Each favours being obviously correct over being fast, so they only
+//! handle inputs far smaller than the real ones.
+
+use std::str::FromStr;
+
+use anyhow::Result;
+use aoc_common::Answer;
+use toml::Table;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+/// Solves one part of a day straight from its input.
+pub type Solver = fn(&str) -> Result<Answer>;
+
+/// The reference solutions to one day.
+pub struct Reference {
+    pub day: u8,
+    /// A generated input size the reference solutions solve quickly.
+    pub size: usize,
+    pub part1: Solver,
+    /// `None` when there is no part 2 to solve.
+    pub part2: Option<Solver>,
+    /// The parameters the real solution is run with to match the reference, where the puzzle's own
+    /// would take far too many steps to solve the simple way.
+    pub params: Table,
+}
+
+impl Reference {
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+macro_rules! reference {
+    ($day:expr, $module:ident, $size:expr) => {
+        Reference {
+            day: $day,
+            size: $size,
+            part1: $module::part1,
+            part2: Some($module::part2),
+            params: Table::new(),
+        }
+    };
+    ($day:expr, $module:ident, $size:expr, with params) => {
+        Reference {
+            day: $day,
+            size: $size,
+            part1: $module::part1,
+            part2: Some($module::part2),
+            params: $module::params(),
+        }
+    };
+    ($day:expr, $module:ident, $size:expr, part1 only) => {
+        Reference {
+            day: $day,
+            size: $size,
+            part1: $module::part1,
+            part2: None,
+            params: Table::new(),
+        }
+    };
+}
+
+pub fn all() -> Vec<Reference> {
+    vec![
+        reference!(1, day01, 50),
+        reference!(2, day02, 100),
+        reference!(3, day03, 50),
+        reference!(4, day04, 50),
+        reference!(5, day05, 100),
+        reference!(6, day06, 50),
+        reference!(7, day07, 12),
+        reference!(8, day08, 50),
+        reference!(9, day09, 100),
+        reference!(10, day10, 15),
+        reference!(11, day11, 20),
+        reference!(12, day12, 100),
+        reference!(13, day13, 6),
+        reference!(14, day14, 30),
+        // Part 2 is 30 million turns whatever the starting numbers, so it's compared on fewer.
+        reference!(15, day15, 7, with params),
+        reference!(16, day16, 30),
+        reference!(17, day17, 4),
+        reference!(18, day18, 50),
+        reference!(19, day19, 50),
+        reference!(20, day20, 3),
+        reference!(21, day21, 30),
+        reference!(22, day22, 10),
+        // Part 2 is 10 million moves whatever the starting cups, so it's compared on fewer.
+        reference!(23, day23, 9, with params),
+        reference!(24, day24, 50),
+        // There's no part 2 puzzle on the last day.
+        reference!(25, day25, 2, part1 only),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Reference> {
+    all().into_iter().find(|reference| reference.day == day)
+}
+
+/// Parses each line of the input as a number.
+fn numbers<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    Ok(input
+        .lines()
+        .map(str::parse)
+        .collect::<Result<_, T::Err>>()?)
+}
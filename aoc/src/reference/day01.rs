@@ -0,0 +1,30 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+use super::numbers;
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let entries: Vec<i64> = numbers(input)?;
+    for (i, a) in entries.iter().enumerate() {
+        for b in &entries[i + 1..] {
+            if a + b == 2020 {
+                return Ok((a * b).into());
+            }
+        }
+    }
+    Err(anyhow!("No two entries sum to 2020"))
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let entries: Vec<i64> = numbers(input)?;
+    for (i, a) in entries.iter().enumerate() {
+        for (j, b) in entries.iter().enumerate().skip(i + 1) {
+            for c in &entries[j + 1..] {
+                if a + b + c == 2020 {
+                    return Ok((a * b * c).into());
+                }
+            }
+        }
+    }
+    Err(anyhow!("No three entries sum to 2020"))
+}
@@ -0,0 +1,46 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// The two numbers, letter and password of a password entry.
+type Entry = (usize, usize, char, Vec<char>);
+
+/// Splits `1-3 a: abcde` into its numbers, letter and password.
+fn entries(input: &str) -> Result<Vec<Entry>> {
+    input
+        .lines()
+        .map(|line| {
+            let parts: Vec<&str> = line.split(['-', ' ', ':']).collect();
+            match parts[..] {
+                [low, high, letter, "", password] => Ok((
+                    low.parse()?,
+                    high.parse()?,
+                    letter.parse()?,
+                    password.chars().collect(),
+                )),
+                _ => Err(anyhow!("Malformed password entry: {}", line)),
+            }
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let mut valid = 0;
+    for (low, high, letter, password) in entries(input)? {
+        let count = password.iter().filter(|&&c| c == letter).count();
+        if low <= count && count <= high {
+            valid += 1;
+        }
+    }
+    Ok(Answer::from(valid as u64))
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut valid = 0;
+    for (first, second, letter, password) in entries(input)? {
+        let at = |position: usize| position >= 1 && password.get(position - 1) == Some(&letter);
+        if at(first) != at(second) {
+            valid += 1;
+        }
+    }
+    Ok(Answer::from(valid as u64))
+}
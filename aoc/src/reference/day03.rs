@@ -0,0 +1,30 @@
+use anyhow::Result;
+use aoc_common::Answer;
+
+fn trees(input: &str, right: usize, down: usize) -> u64 {
+    let rows: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let mut trees = 0;
+    let mut x = 0;
+    let mut y = 0;
+    while y < rows.len() {
+        if rows[y][x % rows[y].len()] == '#' {
+            trees += 1;
+        }
+        x += right;
+        y += down;
+    }
+    trees
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(trees(input, 3, 1).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok((trees(input, 1, 1)
+        * trees(input, 3, 1)
+        * trees(input, 5, 1)
+        * trees(input, 7, 1)
+        * trees(input, 1, 2))
+    .into())
+}
@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use aoc_common::Answer;
+
+/// Each passport's fields by name. Passports are separated by blank lines.
+fn passports(input: &str) -> Vec<HashMap<String, String>> {
+    let mut passports = vec![HashMap::new()];
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            passports.push(HashMap::new());
+            continue;
+        }
+        for field in line.split_whitespace() {
+            let mut parts = field.splitn(2, ':');
+            let name = parts.next().unwrap_or_default().to_owned();
+            let value = parts.next().unwrap_or_default().to_owned();
+            passports.last_mut().expect("non-empty").insert(name, value);
+        }
+    }
+    passports.retain(|passport| !passport.is_empty());
+    passports
+}
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+
+fn has_fields(passport: &HashMap<String, String>) -> bool {
+    REQUIRED.iter().all(|name| passport.contains_key(*name))
+}
+
+fn year_between(value: &str, low: u32, high: u32) -> bool {
+    value.len() == 4
+        && value
+            .parse()
+            .is_ok_and(|year: u32| low <= year && year <= high)
+}
+
+fn is_valid(name: &str, value: &str) -> bool {
+    match name {
+        "byr" => year_between(value, 1920, 2002),
+        "iyr" => year_between(value, 2010, 2020),
+        "eyr" => year_between(value, 2020, 2030),
+        "hgt" => {
+            let (number, unit) = value.split_at(value.len().saturating_sub(2));
+            match (number.parse::<u32>(), unit) {
+                (Ok(height), "cm") => (150..=193).contains(&height),
+                (Ok(height), "in") => (59..=76).contains(&height),
+                _ => false,
+            }
+        }
+        "hcl" => {
+            value.len() == 7
+                && value.starts_with('#')
+                && value[1..].chars().all(|c| "0123456789abcdef".contains(c))
+        }
+        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"].contains(&value),
+        "pid" => value.len() == 9 && value.chars().all(|c| c.is_ascii_digit()),
+        _ => true,
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(passports(input)
+        .iter()
+        .filter(|passport| has_fields(passport))
+        .count()
+        .into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok(passports(input)
+        .iter()
+        .filter(|passport| {
+            has_fields(passport) && passport.iter().all(|(name, value)| is_valid(name, value))
+        })
+        .count()
+        .into())
+}
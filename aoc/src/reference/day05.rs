@@ -0,0 +1,35 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// Reads each boarding pass as a binary number, with `B` and `R` as ones.
+fn seat_ids(input: &str) -> Result<Vec<u32>> {
+    input
+        .lines()
+        .map(|line| {
+            let binary: String = line
+                .chars()
+                .map(|c| if c == 'B' || c == 'R' { '1' } else { '0' })
+                .collect();
+            Ok(u32::from_str_radix(&binary, 2)?)
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let ids = seat_ids(input)?;
+    let highest = ids
+        .iter()
+        .max()
+        .ok_or_else(|| anyhow!("No boarding passes"))?;
+    Ok((*highest).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let ids = seat_ids(input)?;
+    for id in 1..1023 {
+        if !ids.contains(&id) && ids.contains(&(id - 1)) && ids.contains(&(id + 1)) {
+            return Ok(id.into());
+        }
+    }
+    Err(anyhow!("No empty seat between two full ones"))
+}
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use aoc_common::Answer;
+
+/// The lines of each group's answers, split on blank lines.
+fn groups(input: &str) -> Vec<Vec<&str>> {
+    let mut groups = vec![Vec::new()];
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            groups.push(Vec::new());
+        } else {
+            groups.last_mut().expect("non-empty").push(line.trim());
+        }
+    }
+    groups.retain(|group| !group.is_empty());
+    groups
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let mut total = 0;
+    for group in groups(input) {
+        total += ('a'..='z')
+            .filter(|&question| group.iter().any(|person| person.contains(question)))
+            .count();
+    }
+    Ok(total.into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut total = 0;
+    for group in groups(input) {
+        total += ('a'..='z')
+            .filter(|&question| group.iter().all(|person| person.contains(question)))
+            .count();
+    }
+    Ok(total.into())
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// Each bag's color and the counts and colors of the bags directly inside it.
+fn rules(input: &str) -> Result<HashMap<String, Vec<(u64, String)>>> {
+    let mut rules = HashMap::new();
+    for line in input.lines() {
+        let mut halves = line.split(" bags contain ");
+        let color = halves.next().unwrap_or_default().to_owned();
+        let contents = halves
+            .next()
+            .ok_or_else(|| anyhow!("Malformed rule: {}", line))?
+            .trim_end_matches('.');
+        let mut inside = Vec::new();
+        if contents != "no other bags" {
+            for bags in contents.split(", ") {
+                let words: Vec<&str> = bags.split(' ').collect();
+                inside.push((words[0].parse()?, words[1..words.len() - 1].join(" ")));
+            }
+        }
+        rules.insert(color, inside);
+    }
+    Ok(rules)
+}
+
+fn can_hold_shiny_gold(rules: &HashMap<String, Vec<(u64, String)>>, color: &str) -> bool {
+    rules[color]
+        .iter()
+        .any(|(_, inside)| inside == "shiny gold" || can_hold_shiny_gold(rules, inside))
+}
+
+/// Counts every bag inside a bag of `color`, opening each bag one at a time.
+fn bags_inside(rules: &HashMap<String, Vec<(u64, String)>>, color: &str) -> u64 {
+    rules[color]
+        .iter()
+        .map(|(count, inside)| count * (1 + bags_inside(rules, inside)))
+        .sum()
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let rules = rules(input)?;
+    Ok(rules
+        .keys()
+        .filter(|color| can_hold_shiny_gold(&rules, color))
+        .count()
+        .into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok(bags_inside(&rules(input)?, "shiny gold").into())
+}
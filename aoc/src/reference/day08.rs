@@ -0,0 +1,60 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+fn program(input: &str) -> Result<Vec<(String, i64)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (operation, argument) = line
+                .split_once(' ')
+                .ok_or_else(|| anyhow!("Malformed instruction: {}", line))?;
+            Ok((operation.to_owned(), argument.parse()?))
+        })
+        .collect()
+}
+
+/// Runs the program until it runs off the end or repeats an instruction, returning the accumulator
+/// and whether it ran off the end.
+fn run(program: &[(String, i64)]) -> (i64, bool) {
+    let mut accumulator = 0;
+    let mut pointer: i64 = 0;
+    let mut visited = vec![false; program.len()];
+    loop {
+        if pointer == program.len() as i64 {
+            return (accumulator, true);
+        }
+        if pointer < 0 || pointer > program.len() as i64 || visited[pointer as usize] {
+            return (accumulator, false);
+        }
+        visited[pointer as usize] = true;
+        let (operation, argument) = &program[pointer as usize];
+        match operation.as_str() {
+            "acc" => {
+                accumulator += argument;
+                pointer += 1;
+            }
+            "jmp" => pointer += argument,
+            _ => pointer += 1,
+        }
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(run(&program(input)?).0.into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let program = program(input)?;
+    for index in 0..program.len() {
+        let mut fixed = program.clone();
+        fixed[index].0 = match fixed[index].0.as_str() {
+            "jmp" => "nop".to_owned(),
+            "nop" => "jmp".to_owned(),
+            _ => continue,
+        };
+        if let (accumulator, true) = run(&fixed) {
+            return Ok(accumulator.into());
+        }
+    }
+    Err(anyhow!("No single flip makes the program terminate"))
+}
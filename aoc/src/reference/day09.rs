@@ -0,0 +1,40 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+use super::numbers;
+
+const PREAMBLE: usize = 25;
+
+fn first_invalid(numbers: &[u64]) -> Result<u64> {
+    for (index, &number) in numbers.iter().enumerate().skip(PREAMBLE) {
+        let window = &numbers[index - PREAMBLE..index];
+        let valid = window
+            .iter()
+            .any(|&a| window.iter().any(|&b| a != b && a + b == number));
+        if !valid {
+            return Ok(number);
+        }
+    }
+    Err(anyhow!("Every number is valid"))
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(first_invalid(&numbers(input)?)?.into())
+}
+
+/// Tries the shortest runs first and then the earliest, like the real solver, since more than one
+/// run could add up to the invalid number.
+pub fn part2(input: &str) -> Result<Answer> {
+    let numbers: Vec<u64> = numbers(input)?;
+    let invalid = first_invalid(&numbers)?;
+    for length in 2..=numbers.len() {
+        for run in numbers.windows(length) {
+            if run.iter().sum::<u64>() == invalid {
+                let smallest = run.iter().min().expect("non-empty");
+                let largest = run.iter().max().expect("non-empty");
+                return Ok((smallest + largest).into());
+            }
+        }
+    }
+    Err(anyhow!("No run adds up to {}", invalid))
+}
@@ -0,0 +1,57 @@
+use anyhow::{anyhow, ensure, Result};
+use aoc_common::Answer;
+
+use super::numbers;
+
+/// The adapters in order, with the outlet before them and the device after them.
+fn chain(input: &str) -> Result<Vec<u64>> {
+    let mut adapters: Vec<u64> = numbers(input)?;
+    adapters.sort_unstable();
+    let device = adapters.last().ok_or_else(|| anyhow!("No adapters"))? + 3;
+    adapters.insert(0, 0);
+    adapters.push(device);
+    Ok(adapters)
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let chain = chain(input)?;
+    let ones = chain
+        .windows(2)
+        .filter(|pair| pair[1] - pair[0] == 1)
+        .count();
+    let threes = chain
+        .windows(2)
+        .filter(|pair| pair[1] - pair[0] == 3)
+        .count();
+    Ok((ones * threes).into())
+}
+
+/// Tries every subset of the adapters between the outlet and the device, so only works for a few
+/// dozen adapters.
+pub fn part2(input: &str) -> Result<Answer> {
+    let chain = chain(input)?;
+    let optional = &chain[1..chain.len() - 2];
+    ensure!(
+        optional.len() <= 24,
+        "Too many adapters to try every subset"
+    );
+    let mut arrangements: u64 = 0;
+    for subset in 0..1u32 << optional.len() {
+        let mut jolts = 0;
+        let mut connects = true;
+        let used = optional
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| subset & 1 << index != 0)
+            .map(|(_, &adapter)| adapter)
+            .chain(chain[chain.len() - 2..].iter().copied());
+        for adapter in used {
+            connects &= adapter - jolts <= 3;
+            jolts = adapter;
+        }
+        if connects {
+            arrangements += 1;
+        }
+    }
+    Ok(arrangements.into())
+}
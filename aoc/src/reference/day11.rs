@@ -0,0 +1,63 @@
+use anyhow::Result;
+use aoc_common::Answer;
+
+const DIRECTIONS: [(i64, i64); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Runs rounds until nothing changes and counts the occupied seats. Each seat looks at most
+/// `sight` steps in each direction for a seat, and empties when it sees `crowded` occupied seats.
+fn settle(input: &str, sight: i64, crowded: usize) -> usize {
+    let mut seats: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+    let height = seats.len() as i64;
+    let width = seats.first().map_or(0, |row| row.len()) as i64;
+    loop {
+        let mut next = seats.clone();
+        for y in 0..height {
+            for x in 0..width {
+                let mut occupied = 0;
+                for (dy, dx) in DIRECTIONS {
+                    for step in 1..=sight {
+                        let (seen_y, seen_x) = (y + dy * step, x + dx * step);
+                        if seen_y < 0 || seen_y >= height || seen_x < 0 || seen_x >= width {
+                            break;
+                        }
+                        match seats[seen_y as usize][seen_x as usize] {
+                            '#' => {
+                                occupied += 1;
+                                break;
+                            }
+                            'L' => break,
+                            _ => {}
+                        }
+                    }
+                }
+                let seat = &mut next[y as usize][x as usize];
+                if *seat == 'L' && occupied == 0 {
+                    *seat = '#';
+                } else if *seat == '#' && occupied >= crowded {
+                    *seat = 'L';
+                }
+            }
+        }
+        if next == seats {
+            return seats.iter().flatten().filter(|&&seat| seat == '#').count();
+        }
+        seats = next;
+    }
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(settle(input, 1, 4).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok(settle(input, i64::MAX, 5).into())
+}
@@ -0,0 +1,59 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+fn instructions(input: &str) -> Result<Vec<(char, i64)>> {
+    input
+        .lines()
+        .map(|line| {
+            let action = line
+                .chars()
+                .next()
+                .ok_or_else(|| anyhow!("Empty instruction"))?;
+            Ok((action, line[1..].parse()?))
+        })
+        .collect()
+}
+
+/// Turns `(east, north)` a quarter turn right.
+fn turn_right((east, north): (i64, i64)) -> (i64, i64) {
+    (north, -east)
+}
+
+/// Moves the ship itself with `N`, `S`, `E` and `W` when `waypoint` is false, or otherwise the
+/// waypoint that `F` moves the ship towards.
+fn navigate(input: &str, mut heading: (i64, i64), waypoint: bool) -> Result<Answer> {
+    let mut ship = (0, 0);
+    for (action, value) in instructions(input)? {
+        let moved = if waypoint { &mut heading } else { &mut ship };
+        match action {
+            'N' => moved.1 += value,
+            'S' => moved.1 -= value,
+            'E' => moved.0 += value,
+            'W' => moved.0 -= value,
+            'R' | 'L' => {
+                let quarter_turns = if action == 'R' {
+                    value / 90
+                } else {
+                    4 - value / 90
+                };
+                for _ in 0..quarter_turns {
+                    heading = turn_right(heading);
+                }
+            }
+            'F' => {
+                ship.0 += heading.0 * value;
+                ship.1 += heading.1 * value;
+            }
+            _ => return Err(anyhow!("Unknown action: {}", action)),
+        }
+    }
+    Ok((ship.0.abs() + ship.1.abs()).into())
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    navigate(input, (1, 0), false)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    navigate(input, (10, 1), true)
+}
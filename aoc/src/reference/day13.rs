@@ -0,0 +1,49 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// The earliest departure and each bus id with its position in the list.
+fn notes(input: &str) -> Result<(u64, Vec<(u64, u64)>)> {
+    let mut lines = input.lines();
+    let earliest = lines
+        .next()
+        .ok_or_else(|| anyhow!("No timestamp"))?
+        .parse()?;
+    let mut buses = Vec::new();
+    for (offset, bus) in lines
+        .next()
+        .ok_or_else(|| anyhow!("No buses"))?
+        .split(',')
+        .enumerate()
+    {
+        if bus != "x" {
+            buses.push((offset as u64, bus.parse()?));
+        }
+    }
+    Ok((earliest, buses))
+}
+
+/// Waits one minute at a time for the first bus to leave.
+pub fn part1(input: &str) -> Result<Answer> {
+    let (earliest, buses) = notes(input)?;
+    for time in earliest.. {
+        if let Some((_, bus)) = buses.iter().find(|(_, bus)| time % bus == 0) {
+            return Ok(((time - earliest) * bus).into());
+        }
+    }
+    unreachable!()
+}
+
+/// Lines the buses up one at a time: once a time suits the buses so far, stepping by the product
+/// of their ids keeps them all in line while looking for a time that suits the next bus too.
+pub fn part2(input: &str) -> Result<Answer> {
+    let (_, buses) = notes(input)?;
+    let mut time: u64 = 0;
+    let mut step = 1;
+    for (offset, bus) in buses {
+        while !(time + offset).is_multiple_of(bus) {
+            time += step;
+        }
+        step *= bus;
+    }
+    Ok(time.into())
+}
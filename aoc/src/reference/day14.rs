@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// A mask and the addresses and values written while it is set.
+type Block = (String, Vec<(u64, u64)>);
+
+/// Each mask line with the writes that follow it.
+fn program(input: &str) -> Result<Vec<Block>> {
+    let mut program: Vec<Block> = Vec::new();
+    for line in input.lines() {
+        if let Some(mask) = line.strip_prefix("mask = ") {
+            program.push((mask.to_owned(), Vec::new()));
+        } else {
+            let (address, value) = line
+                .strip_prefix("mem[")
+                .and_then(|line| line.split_once("] = "))
+                .ok_or_else(|| anyhow!("Invalid instruction: {}", line))?;
+            program
+                .last_mut()
+                .ok_or_else(|| anyhow!("Write before the first mask"))?
+                .1
+                .push((address.parse()?, value.parse()?));
+        }
+    }
+    Ok(program)
+}
+
+/// The value of bit `bit` (counting from the least significant) after `mask` is applied to it.
+fn mask_bit(mask: &str, bit: usize) -> char {
+    mask.as_bytes()[mask.len() - 1 - bit] as char
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let mut memory = HashMap::new();
+    for (mask, writes) in program(input)? {
+        for (address, value) in writes {
+            let mut masked = 0;
+            for bit in 0..36 {
+                let set = match mask_bit(&mask, bit) {
+                    '1' => true,
+                    '0' => false,
+                    _ => value & (1 << bit) != 0,
+                };
+                if set {
+                    masked |= 1 << bit;
+                }
+            }
+            memory.insert(address, masked);
+        }
+    }
+    Ok(memory.values().sum::<u64>().into())
+}
+
+/// Every address `address` decodes to from bit `bit` upwards.
+fn addresses(mask: &str, address: u64, bit: usize) -> Vec<u64> {
+    if bit == 36 {
+        return vec![address];
+    }
+    match mask_bit(mask, bit) {
+        '1' => addresses(mask, address | (1 << bit), bit + 1),
+        '0' => addresses(mask, address, bit + 1),
+        _ => {
+            let mut decoded = addresses(mask, address & !(1 << bit), bit + 1);
+            decoded.extend(addresses(mask, address | (1 << bit), bit + 1));
+            decoded
+        }
+    }
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut memory = HashMap::new();
+    for (mask, writes) in program(input)? {
+        for (address, value) in writes {
+            for address in addresses(&mask, address, 0) {
+                memory.insert(address, value);
+            }
+        }
+    }
+    Ok(memory.values().sum::<u64>().into())
+}
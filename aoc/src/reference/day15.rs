@@ -0,0 +1,40 @@
+use anyhow::Result;
+use aoc_common::Answer;
+use toml::Table;
+
+/// The turn part 2 asks for when comparing, since the puzzle's 30 million is far too many to play
+/// this way.
+const PART2_TURNS: usize = 3000;
+
+pub fn params() -> Table {
+    let mut params = Table::new();
+    params.insert("part2_turns".to_string(), (PART2_TURNS as i64).into());
+    params
+}
+
+/// Plays `turns` turns, looking back through every number spoken so far to find the last time the
+/// previous number was spoken.
+fn play(input: &str, turns: usize) -> Result<Answer> {
+    let mut spoken = input
+        .trim()
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<Vec<u64>, _>>()?;
+    while spoken.len() < turns {
+        let (&last, before) = spoken.split_last().expect("starting numbers are spoken");
+        let next = match before.iter().rposition(|&number| number == last) {
+            Some(turn) => (before.len() - turn) as u64,
+            None => 0,
+        };
+        spoken.push(next);
+    }
+    Ok(spoken[turns - 1].into())
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    play(input, 2020)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    play(input, PART2_TURNS)
+}
@@ -0,0 +1,135 @@
+use std::ops::RangeInclusive;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+struct Notes {
+    rules: Vec<(String, [RangeInclusive<u64>; 2])>,
+    your_ticket: Vec<u64>,
+    nearby_tickets: Vec<Vec<u64>>,
+}
+
+fn ticket(line: &str) -> Result<Vec<u64>> {
+    Ok(line.split(',').map(str::parse).collect::<Result<_, _>>()?)
+}
+
+fn notes(input: &str) -> Result<Notes> {
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    if sections.len() != 3 {
+        return Err(anyhow!("Expected three sections, got {}", sections.len()));
+    }
+    let mut rules = Vec::new();
+    for line in sections[0].lines() {
+        let (field, ranges) = line
+            .split_once(": ")
+            .ok_or_else(|| anyhow!("Invalid rule: {}", line))?;
+        let mut bounds = ranges
+            .split(" or ")
+            .flat_map(|range| range.split('-'))
+            .map(str::parse);
+        let mut bound = || -> Result<u64> {
+            Ok(bounds
+                .next()
+                .ok_or_else(|| anyhow!("Invalid rule: {}", line))??)
+        };
+        rules.push((field.to_owned(), [bound()?..=bound()?, bound()?..=bound()?]));
+    }
+    let your_ticket = ticket(
+        sections[1]
+            .lines()
+            .nth(1)
+            .ok_or_else(|| anyhow!("No ticket"))?,
+    )?;
+    let nearby_tickets = sections[2]
+        .lines()
+        .skip(1)
+        .map(ticket)
+        .collect::<Result<_>>()?;
+    Ok(Notes {
+        rules,
+        your_ticket,
+        nearby_tickets,
+    })
+}
+
+fn valid_for_any(notes: &Notes, value: u64) -> bool {
+    notes
+        .rules
+        .iter()
+        .any(|(_, ranges)| ranges.iter().any(|range| range.contains(&value)))
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let notes = notes(input)?;
+    let invalid: u64 = notes
+        .nearby_tickets
+        .iter()
+        .flatten()
+        .filter(|&&value| !valid_for_any(&notes, value))
+        .sum();
+    Ok(invalid.into())
+}
+
+/// Places every field without a position at an unused position that suits it, trying every
+/// possibility until they all fit. The field with the fewest positions left is placed first, so
+/// wrong guesses are found quickly.
+fn assign(suits: &[Vec<bool>], positions: &mut Vec<Option<usize>>) -> bool {
+    let free = |field: usize| -> Vec<usize> {
+        (0..suits[field].len())
+            .filter(|&position| suits[field][position] && !positions.contains(&Some(position)))
+            .collect()
+    };
+    let field = match (0..suits.len())
+        .filter(|&field| positions[field].is_none())
+        .min_by_key(|&field| free(field).len())
+    {
+        Some(field) => field,
+        None => return true,
+    };
+    for position in free(field) {
+        positions[field] = Some(position);
+        if assign(suits, positions) {
+            return true;
+        }
+        positions[field] = None;
+    }
+    false
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let notes = notes(input)?;
+    let mut tickets: Vec<&Vec<u64>> = notes
+        .nearby_tickets
+        .iter()
+        .filter(|ticket| ticket.iter().all(|&value| valid_for_any(&notes, value)))
+        .collect();
+    tickets.push(&notes.your_ticket);
+    let suits: Vec<Vec<bool>> = notes
+        .rules
+        .iter()
+        .map(|(_, ranges)| {
+            (0..notes.your_ticket.len())
+                .map(|position| {
+                    tickets
+                        .iter()
+                        .all(|ticket| ranges.iter().any(|range| range.contains(&ticket[position])))
+                })
+                .collect()
+        })
+        .collect();
+    let mut positions = vec![None; suits.len()];
+    if !assign(&suits, &mut positions) {
+        return Err(anyhow!("The fields can't all be placed"));
+    }
+    let departures: Vec<u64> = notes
+        .rules
+        .iter()
+        .zip(positions.into_iter().flatten())
+        .filter(|((field, _), _)| field.starts_with("departure"))
+        .map(|(_, position)| notes.your_ticket[position])
+        .collect();
+    if departures.len() != 6 {
+        return Err(anyhow!("Expected six departure fields"));
+    }
+    Ok(departures.iter().product::<u64>().into())
+}
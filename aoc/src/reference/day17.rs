@@ -0,0 +1,57 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use aoc_common::Answer;
+
+/// Runs six cycles in `dimensions` dimensions, counting each cycle how many active cubes every cube
+/// is next to.
+fn boot(input: &str, dimensions: usize) -> usize {
+    let mut active: HashSet<Vec<i64>> = HashSet::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, cube) in line.chars().enumerate() {
+            if cube == '#' {
+                let mut position = vec![0; dimensions];
+                position[0] = x as i64;
+                position[1] = y as i64;
+                active.insert(position);
+            }
+        }
+    }
+    let mut offsets: Vec<Vec<i64>> = vec![vec![]];
+    for _ in 0..dimensions {
+        offsets = offsets
+            .into_iter()
+            .flat_map(|offset| {
+                (-1..=1).map(move |delta| {
+                    let mut offset = offset.clone();
+                    offset.push(delta);
+                    offset
+                })
+            })
+            .collect();
+    }
+    offsets.retain(|offset| offset.iter().any(|&delta| delta != 0));
+    for _ in 0..6 {
+        let mut neighbors: HashMap<Vec<i64>, usize> = HashMap::new();
+        for position in &active {
+            for offset in &offsets {
+                let neighbor = position.iter().zip(offset).map(|(a, b)| a + b).collect();
+                *neighbors.entry(neighbor).or_default() += 1;
+            }
+        }
+        active = neighbors
+            .into_iter()
+            .filter(|(position, count)| *count == 3 || (*count == 2 && active.contains(position)))
+            .map(|(position, _)| position)
+            .collect();
+    }
+    active.len()
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(boot(input, 3).into())
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    Ok(boot(input, 4).into())
+}
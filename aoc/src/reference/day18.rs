@@ -0,0 +1,61 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// Evaluates an expression without parentheses, applying the operators in `precedence` order and
+/// left to right within each group.
+fn evaluate_flat(tokens: &[&str], precedence: &[&[&str]]) -> Result<i64> {
+    let mut tokens: Vec<String> = tokens.iter().map(|&token| token.to_owned()).collect();
+    for operators in precedence {
+        while let Some(index) = tokens
+            .iter()
+            .position(|token| operators.contains(&token.as_str()))
+        {
+            if index == 0 || index + 1 >= tokens.len() {
+                return Err(anyhow!("Operator without operands"));
+            }
+            let left: i64 = tokens[index - 1].parse()?;
+            let right: i64 = tokens[index + 1].parse()?;
+            let value = if tokens[index] == "+" {
+                left + right
+            } else {
+                left * right
+            };
+            tokens.splice(index - 1..=index + 1, [value.to_string()]);
+        }
+    }
+    match tokens.as_slice() {
+        [value] => Ok(value.parse()?),
+        _ => Err(anyhow!("Expected a single value")),
+    }
+}
+
+/// Replaces the innermost parenthesised expressions with their values until none are left.
+fn evaluate(line: &str, precedence: &[&[&str]]) -> Result<i64> {
+    let mut line = line.replace('(', "( ").replace(')', " )");
+    while let Some(close) = line.find(')') {
+        let open = line[..close]
+            .rfind('(')
+            .ok_or_else(|| anyhow!("Unbalanced parentheses"))?;
+        let tokens: Vec<&str> = line[open + 1..close].split_whitespace().collect();
+        let value = evaluate_flat(&tokens, precedence)?;
+        line.replace_range(open..=close, &value.to_string());
+    }
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    evaluate_flat(&tokens, precedence)
+}
+
+fn sum(input: &str, precedence: &[&[&str]]) -> Result<Answer> {
+    let mut sum = 0;
+    for line in input.lines() {
+        sum += evaluate(line, precedence)?;
+    }
+    Ok(sum.into())
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    sum(input, &[&["+", "*"]])
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    sum(input, &[&["+"], &["*"]])
+}
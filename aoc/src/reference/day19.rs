@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+enum Rule {
+    Char(u8),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+fn rules_and_messages(input: &str) -> Result<(HashMap<usize, Rule>, Vec<&str>)> {
+    let (rules, messages) = input
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow!("Expected rules and messages"))?;
+    let mut parsed = HashMap::new();
+    for line in rules.lines() {
+        let (number, rule) = line
+            .split_once(": ")
+            .ok_or_else(|| anyhow!("Invalid rule: {}", line))?;
+        let rule = if let Some(quoted) = rule.strip_prefix('"') {
+            Rule::Char(quoted.as_bytes()[0])
+        } else {
+            Rule::Alternatives(
+                rule.split(" | ")
+                    .map(|sequence| {
+                        sequence
+                            .split(' ')
+                            .map(str::parse)
+                            .collect::<Result<_, _>>()
+                    })
+                    .collect::<Result<_, _>>()?,
+            )
+        };
+        parsed.insert(number.parse()?, rule);
+    }
+    Ok((parsed, messages.lines().collect()))
+}
+
+/// Every position in `message` that matching `rule` from `start` can end at. Each rule consumes
+/// at least one character, so even the looping rules of part 2 run out of message to match.
+fn ends(rules: &HashMap<usize, Rule>, rule: usize, message: &[u8], start: usize) -> Vec<usize> {
+    match &rules[&rule] {
+        Rule::Char(c) => {
+            if message.get(start) == Some(c) {
+                vec![start + 1]
+            } else {
+                vec![]
+            }
+        }
+        Rule::Alternatives(alternatives) => {
+            let mut all_ends = Vec::new();
+            for sequence in alternatives {
+                let mut positions = vec![start];
+                for &rule in sequence {
+                    positions = positions
+                        .into_iter()
+                        .filter(|&position| position < message.len())
+                        .flat_map(|position| ends(rules, rule, message, position))
+                        .collect();
+                }
+                all_ends.extend(positions);
+            }
+            all_ends
+        }
+    }
+}
+
+fn count_matches(rules: &HashMap<usize, Rule>, messages: &[&str]) -> Answer {
+    messages
+        .iter()
+        .filter(|message| ends(rules, 0, message.as_bytes(), 0).contains(&message.len()))
+        .count()
+        .into()
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let (rules, messages) = rules_and_messages(input)?;
+    Ok(count_matches(&rules, &messages))
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let (mut rules, messages) = rules_and_messages(input)?;
+    rules.insert(8, Rule::Alternatives(vec![vec![42], vec![42, 8]]));
+    rules.insert(11, Rule::Alternatives(vec![vec![42, 31], vec![42, 11, 31]]));
+    Ok(count_matches(&rules, &messages))
+}
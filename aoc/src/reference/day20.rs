@@ -0,0 +1,163 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+use day20::SEA_MONSTER;
+
+type Image = Vec<Vec<char>>;
+
+fn tiles(input: &str) -> Result<Vec<(u64, Image)>> {
+    input
+        .split("\n\n")
+        .filter(|tile| !tile.trim().is_empty())
+        .map(|tile| {
+            let mut lines = tile.lines();
+            let id = lines
+                .next()
+                .and_then(|line| line.strip_prefix("Tile "))
+                .and_then(|line| line.strip_suffix(':'))
+                .ok_or_else(|| anyhow!("Expected a tile id"))?
+                .parse()?;
+            Ok((id, lines.map(|line| line.chars().collect()).collect()))
+        })
+        .collect()
+}
+
+fn rotate(image: &Image) -> Image {
+    let size = image.len();
+    (0..size)
+        .map(|y| (0..size).map(|x| image[size - 1 - x][y]).collect())
+        .collect()
+}
+
+/// The image rotated to each of its four turns and then each of those flipped over.
+fn orientations(image: &Image) -> Vec<Image> {
+    let mut orientations = vec![image.clone()];
+    for _ in 0..3 {
+        orientations.push(rotate(orientations.last().expect("not empty")));
+    }
+    let flipped: Vec<Image> = orientations
+        .iter()
+        .map(|image| {
+            image
+                .iter()
+                .map(|row| row.iter().rev().copied().collect())
+                .collect()
+        })
+        .collect();
+    orientations.extend(flipped);
+    orientations
+}
+
+fn top(image: &Image) -> Vec<char> {
+    image[0].clone()
+}
+
+fn bottom(image: &Image) -> Vec<char> {
+    image[image.len() - 1].clone()
+}
+
+fn left(image: &Image) -> Vec<char> {
+    image.iter().map(|row| row[0]).collect()
+}
+
+fn right(image: &Image) -> Vec<char> {
+    image.iter().map(|row| row[row.len() - 1]).collect()
+}
+
+/// Multiplies the ids of the tiles with only two edges that match an edge of another tile.
+pub fn part1(input: &str) -> Result<Answer> {
+    let tiles = tiles(input)?;
+    let mut product: u64 = 1;
+    for (id, image) in &tiles {
+        let matching = [top(image), bottom(image), left(image), right(image)]
+            .iter()
+            .filter(|edge| {
+                tiles.iter().any(|(other_id, other)| {
+                    other_id != id && orientations(other).iter().any(|other| top(other) == **edge)
+                })
+            })
+            .count();
+        if matching == 2 {
+            product *= id;
+        }
+    }
+    Ok(product.into())
+}
+
+/// Fills the grid left to right and top to bottom from `placed.len()` onwards, trying every
+/// unused tile in every orientation that lines up with the tiles above and to the left.
+fn assemble(tiles: &[(u64, Image)], side: usize, placed: &mut Vec<(usize, Image)>) -> bool {
+    let position = placed.len();
+    if position == tiles.len() {
+        return true;
+    }
+    for (index, (_, image)) in tiles.iter().enumerate() {
+        if placed.iter().any(|&(used, _)| used == index) {
+            continue;
+        }
+        for orientation in orientations(image) {
+            if !position.is_multiple_of(side)
+                && right(&placed[position - 1].1) != left(&orientation)
+            {
+                continue;
+            }
+            if position >= side && bottom(&placed[position - side].1) != top(&orientation) {
+                continue;
+            }
+            placed.push((index, orientation));
+            if assemble(tiles, side, placed) {
+                return true;
+            }
+            placed.pop();
+        }
+    }
+    false
+}
+
+/// Assembles the image, strips the tile borders and counts the rough water outside any monster.
+pub fn part2(input: &str) -> Result<Answer> {
+    let tiles = tiles(input)?;
+    let side = (1..=tiles.len())
+        .find(|side| side * side == tiles.len())
+        .ok_or_else(|| anyhow!("The tiles don't make a square"))?;
+    let mut placed = Vec::new();
+    if !assemble(&tiles, side, &mut placed) {
+        return Err(anyhow!("The tiles don't fit together"));
+    }
+    let tile_size = tiles[0].1.len() - 2;
+    let mut image: Image = vec![Vec::new(); side * tile_size];
+    for (position, (_, tile)) in placed.iter().enumerate() {
+        for (y, row) in tile[1..=tile_size].iter().enumerate() {
+            image[position / side * tile_size + y].extend(&row[1..=tile_size]);
+        }
+    }
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.char_indices()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    for image in orientations(&image) {
+        let mut monster_pixels = HashSet::new();
+        for y in 0..=image.len() - SEA_MONSTER.len() {
+            for x in 0..=image.len() - SEA_MONSTER[0].len() {
+                if monster.iter().all(|&(dx, dy)| image[y + dy][x + dx] == '#') {
+                    monster_pixels.extend(monster.iter().map(|&(dx, dy)| (x + dx, y + dy)));
+                }
+            }
+        }
+        if !monster_pixels.is_empty() {
+            let rough = image
+                .iter()
+                .flatten()
+                .filter(|&&pixel| pixel == '#')
+                .count();
+            return Ok((rough - monster_pixels.len()).into());
+        }
+    }
+    Err(anyhow!("No sea monsters in any orientation"))
+}
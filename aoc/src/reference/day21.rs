@@ -0,0 +1,82 @@
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+fn foods(input: &str) -> Result<Vec<(Vec<&str>, Vec<&str>)>> {
+    input
+        .lines()
+        .map(|line| {
+            let (ingredients, allergens) = line
+                .strip_suffix(')')
+                .and_then(|line| line.split_once(" (contains "))
+                .ok_or_else(|| anyhow!("Invalid food: {}", line))?;
+            Ok((
+                ingredients.split(' ').collect(),
+                allergens.split(", ").collect(),
+            ))
+        })
+        .collect()
+}
+
+/// For each allergen, the ingredients found in every food that lists it.
+fn candidates<'a>(foods: &[(Vec<&'a str>, Vec<&'a str>)]) -> BTreeMap<&'a str, Vec<&'a str>> {
+    let mut candidates = BTreeMap::new();
+    for (ingredients, allergens) in foods {
+        for &allergen in allergens {
+            candidates.entry(allergen).or_insert_with(|| {
+                ingredients
+                    .iter()
+                    .copied()
+                    .filter(|ingredient| {
+                        foods.iter().all(|(ingredients, allergens)| {
+                            !allergens.contains(&allergen) || ingredients.contains(ingredient)
+                        })
+                    })
+                    .collect()
+            });
+        }
+    }
+    candidates
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    let foods = foods(input)?;
+    let suspicious: HashSet<&str> = candidates(&foods).into_values().flatten().collect();
+    Ok(foods
+        .iter()
+        .flat_map(|(ingredients, _)| ingredients)
+        .filter(|ingredient| !suspicious.contains(*ingredient))
+        .count()
+        .into())
+}
+
+/// Gives each allergen from `assigned.len()` onwards, in alphabetical order, a different one of
+/// its candidate ingredients, trying every choice until they all have one.
+fn assign<'a>(candidates: &[Vec<&'a str>], assigned: &mut Vec<&'a str>) -> bool {
+    let Some(ingredients) = candidates.get(assigned.len()) else {
+        return true;
+    };
+    for &ingredient in ingredients {
+        if !assigned.contains(&ingredient) {
+            assigned.push(ingredient);
+            if assign(candidates, assigned) {
+                return true;
+            }
+            assigned.pop();
+        }
+    }
+    false
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    let foods = foods(input)?;
+    let candidates: Vec<Vec<&str>> = candidates(&foods).into_values().collect();
+    let mut assigned = Vec::new();
+    if !assign(&candidates, &mut assigned) {
+        return Err(anyhow!(
+            "The allergens can't all be matched to an ingredient"
+        ));
+    }
+    Ok(assigned.join(",").into())
+}
@@ -0,0 +1,70 @@
+use std::collections::{HashSet, VecDeque};
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+fn decks(input: &str) -> Result<[VecDeque<usize>; 2]> {
+    let (player1, player2) = input
+        .split_once("\n\n")
+        .ok_or_else(|| anyhow!("Expected two decks"))?;
+    let deck = |deck: &str| -> Result<VecDeque<usize>> {
+        Ok(deck
+            .lines()
+            .skip(1)
+            .map(str::parse)
+            .collect::<Result<_, _>>()?)
+    };
+    Ok([deck(player1)?, deck(player2)?])
+}
+
+/// Plays a game and returns the index of the winner, leaving the cards in `decks`.
+fn play(decks: &mut [VecDeque<usize>; 2], recursive: bool) -> usize {
+    let mut seen = HashSet::new();
+    while !decks[0].is_empty() && !decks[1].is_empty() {
+        if recursive && !seen.insert(decks.clone()) {
+            return 0;
+        }
+        let cards = [
+            decks[0].pop_front().expect("not empty"),
+            decks[1].pop_front().expect("not empty"),
+        ];
+        let winner = if recursive && decks[0].len() >= cards[0] && decks[1].len() >= cards[1] {
+            let mut copies = [
+                decks[0].iter().take(cards[0]).copied().collect(),
+                decks[1].iter().take(cards[1]).copied().collect(),
+            ];
+            play(&mut copies, true)
+        } else if cards[0] > cards[1] {
+            0
+        } else {
+            1
+        };
+        decks[winner].push_back(cards[winner]);
+        decks[winner].push_back(cards[1 - winner]);
+    }
+    if decks[0].is_empty() {
+        1
+    } else {
+        0
+    }
+}
+
+fn score(input: &str, recursive: bool) -> Result<Answer> {
+    let mut decks = decks(input)?;
+    let winner = play(&mut decks, recursive);
+    Ok(decks[winner]
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, card)| (index + 1) * card)
+        .sum::<usize>()
+        .into())
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    score(input, false)
+}
+
+pub fn part2(input: &str) -> Result<Answer> {
+    score(input, true)
+}
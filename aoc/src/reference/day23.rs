@@ -0,0 +1,75 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+use toml::Table;
+
+/// The moves part 2 makes when comparing, since every move shifts the million cups along.
+const PART2_MOVES: usize = 10;
+
+pub fn params() -> Table {
+    let mut params = Table::new();
+    params.insert("part2_moves".to_string(), (PART2_MOVES as i64).into());
+    params
+}
+
+fn parse(input: &str) -> Result<Vec<u32>> {
+    input
+        .trim()
+        .chars()
+        .map(|cup| {
+            cup.to_digit(10)
+                .ok_or_else(|| anyhow!("Invalid cup: {}", cup))
+        })
+        .collect()
+}
+
+/// Makes `moves` moves on a `Vec`, keeping the current cup at the front by rotating after each
+/// move, then rotates cup 1 to the front.
+fn play(cups: &mut Vec<u32>, moves: usize) -> Result<()> {
+    let highest = *cups.iter().max().ok_or_else(|| anyhow!("No cups"))?;
+    for _ in 0..moves {
+        let current = cups[0];
+        let picked: Vec<u32> = cups.drain(1..4).collect();
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 {
+                highest
+            } else {
+                destination - 1
+            };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+        let index = cups
+            .iter()
+            .position(|&cup| cup == destination)
+            .expect("destination is on the table");
+        cups.splice(index + 1..index + 1, picked);
+        cups.rotate_left(1);
+    }
+    let one = cups
+        .iter()
+        .position(|&cup| cup == 1)
+        .ok_or_else(|| anyhow!("No cup 1"))?;
+    cups.rotate_left(one);
+    Ok(())
+}
+
+/// Plays 100 moves and reads the labels after cup 1.
+pub fn part1(input: &str) -> Result<Answer> {
+    let mut cups = parse(input)?;
+    play(&mut cups, 100)?;
+    Ok(cups[1..]
+        .iter()
+        .fold(0, |labels: u64, &cup| labels * 10 + u64::from(cup))
+        .into())
+}
+
+/// Adds cups up to a million and multiplies the labels of the two cups after cup 1.
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut cups = parse(input)?;
+    let highest = *cups.iter().max().ok_or_else(|| anyhow!("No cups"))?;
+    cups.extend(highest + 1..=1_000_000);
+    play(&mut cups, PART2_MOVES)?;
+    Ok((u64::from(cups[1]) * u64::from(cups[2])).into())
+}
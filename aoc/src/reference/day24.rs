@@ -0,0 +1,60 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+/// Each direction as a step in cube coordinates.
+const DIRECTIONS: [(&str, (i64, i64, i64)); 6] = [
+    ("e", (1, -1, 0)),
+    ("w", (-1, 1, 0)),
+    ("ne", (1, 0, -1)),
+    ("nw", (0, 1, -1)),
+    ("se", (0, -1, 1)),
+    ("sw", (-1, 0, 1)),
+];
+
+fn black_tiles(input: &str) -> Result<HashSet<(i64, i64, i64)>> {
+    let mut black = HashSet::new();
+    for line in input.lines() {
+        let mut tile = (0, 0, 0);
+        let mut rest = line;
+        while !rest.is_empty() {
+            let (direction, step) = DIRECTIONS
+                .iter()
+                .filter(|(direction, _)| rest.starts_with(direction))
+                .max_by_key(|(direction, _)| direction.len())
+                .ok_or_else(|| anyhow!("Invalid directions: {}", line))?;
+            tile = (tile.0 + step.0, tile.1 + step.1, tile.2 + step.2);
+            rest = &rest[direction.len()..];
+        }
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+    Ok(black)
+}
+
+pub fn part1(input: &str) -> Result<Answer> {
+    Ok(black_tiles(input)?.len().into())
+}
+
+/// Flips the tiles for 100 days, counting each day how many black tiles every tile is next to.
+pub fn part2(input: &str) -> Result<Answer> {
+    let mut black = black_tiles(input)?;
+    for _ in 0..100 {
+        let mut neighbors: HashMap<(i64, i64, i64), usize> = HashMap::new();
+        for tile in &black {
+            for (_, step) in DIRECTIONS {
+                *neighbors
+                    .entry((tile.0 + step.0, tile.1 + step.1, tile.2 + step.2))
+                    .or_default() += 1;
+            }
+        }
+        black = neighbors
+            .into_iter()
+            .filter(|(tile, count)| *count == 2 || (*count == 1 && black.contains(tile)))
+            .map(|(tile, _)| tile)
+            .collect();
+    }
+    Ok(black.len().into())
+}
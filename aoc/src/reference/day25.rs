@@ -0,0 +1,30 @@
+use anyhow::{anyhow, Result};
+use aoc_common::Answer;
+
+use super::numbers;
+
+const MODULUS: u64 = 20201227;
+
+/// Transforms the subject number 7 one loop at a time until it gives the card's public key, then
+/// transforms the door's public key that many times.
+pub fn part1(input: &str) -> Result<Answer> {
+    let keys: Vec<u64> = numbers(input)?;
+    let (card, door) = match keys.as_slice() {
+        &[card, door] => (card, door),
+        _ => return Err(anyhow!("Expected two public keys")),
+    };
+    let mut value = 1;
+    let mut loop_size = 0;
+    while value != card {
+        if loop_size == MODULUS {
+            return Err(anyhow!("No loop size gives the card's public key"));
+        }
+        value = value * 7 % MODULUS;
+        loop_size += 1;
+    }
+    let mut encryption_key = 1;
+    for _ in 0..loop_size {
+        encryption_key = encryption_key * door % MODULUS;
+    }
+    Ok(encryption_key.into())
+}
@@ -0,0 +1,300 @@
+use std::io::{BufRead, Write};
+
+use anyhow::{anyhow, bail, Result};
+use aoc_common::output::{format_parse, format_part};
+use aoc_common::{catch_panic, time, Solution};
+use toml::Table;
+
+use crate::days::parse_params;
+use crate::params::Override;
+
+pub mod day07;
+pub mod day16;
+pub mod day21;
+
+/// One way of printing a day's parsed input, chosen with `show <name>`.
+pub struct View<I> {
+    pub name: &'static str,
+    /// The argument the view takes, if any, like `[color]`.
+    pub args: &'static str,
+    pub about: &'static str,
+    pub show: fn(&I, &str) -> Result<String>,
+}
+
+/// A day's parsed input being explored one command at a time.
+pub trait Session {
+    fn day(&self) -> u8;
+
+    /// Runs one command line and returns what to print.
+    fn eval(&mut self, line: &str) -> Result<String>;
+}
+
+struct DaySession<S: Solution> {
+    input: S::Input,
+    params: Table,
+    views: Vec<View<S::Input>>,
+}
+
+/// Parses `input` and starts a session exploring it with `params` set and the day's `views`.
+/// Returns the session and what to print about parsing.
+pub fn start<S>(
+    input: &str,
+    params: Table,
+    views: Vec<View<S::Input>>,
+) -> Result<(Box<dyn Session>, String)>
+where
+    S: Solution + 'static,
+    S::Input: 'static,
+{
+    parse_params::<S>(&params)?;
+    let (input, elapsed) = time(|| catch_panic(|| S::parse(input)));
+    let parsed = format_parse(input.as_ref().err(), elapsed);
+    let session = DaySession::<S> {
+        input: input?,
+        params,
+        views,
+    };
+    Ok((Box::new(session), parsed))
+}
+
+impl<S: Solution> DaySession<S> {
+    /// The parameters set in the session with `overrides` applied, checked to be valid.
+    fn with_overrides(&self, overrides: &str) -> Result<Table> {
+        let mut params = self.params.clone();
+        for param in overrides.split_whitespace() {
+            let param: Override = param.parse()?;
+            if param.day.is_some_and(|day| day != S::DAY) {
+                bail!("Only day {}'s parameters can be set", S::DAY);
+            }
+            params.insert(param.name, param.value);
+        }
+        parse_params::<S>(&params)?;
+        Ok(params)
+    }
+
+    fn help(&self) -> String {
+        let mut help = String::from(
+            "Commands:\n  \
+             show <view> [argument]   Prints a view of the parsed input\n  \
+             part1 [name=value ...]   Solves part 1, changing parameters for this run only\n  \
+             part2 [name=value ...]   Solves part 2, changing parameters for this run only\n  \
+             set name=value ...       Changes parameters for the rest of the session\n  \
+             params                   Prints the parameters that have been changed\n  \
+             help                     Prints this help\n  \
+             quit                     Ends the session\n",
+        );
+        if self.views.is_empty() {
+            help.push_str(&format!("\nDay {} has no views yet.", S::DAY));
+        } else {
+            help.push_str("\nViews:");
+            for view in &self.views {
+                let usage = format!("{} {}", view.name, view.args);
+                help.push_str(&format!("\n  {:<24} {}", usage, view.about));
+            }
+        }
+        help
+    }
+}
+
+impl<S: Solution> Session for DaySession<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn eval(&mut self, line: &str) -> Result<String> {
+        let line = line.trim();
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+        let args = args.trim();
+        match command {
+            "help" => Ok(self.help()),
+            "show" => {
+                let (name, args) = args.split_once(' ').unwrap_or((args, ""));
+                let view = self
+                    .views
+                    .iter()
+                    .find(|view| view.name == name)
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "There is no view named {:?}. Type help to list day {}'s views",
+                            name,
+                            S::DAY
+                        )
+                    })?;
+                (view.show)(&self.input, args.trim())
+            }
+            "part1" | "part2" => {
+                let params = parse_params::<S>(&self.with_overrides(args)?)?;
+                let (part, (answer, elapsed)) = match command {
+                    "part1" => (1, time(|| catch_panic(|| S::part1(&self.input, &params)))),
+                    _ => (2, time(|| catch_panic(|| S::part2(&self.input, &params)))),
+                };
+                Ok(format_part(part, &answer, elapsed))
+            }
+            "set" => {
+                if args.is_empty() {
+                    bail!("Expected parameters to set, like preamble=5");
+                }
+                self.params = self.with_overrides(args)?;
+                Ok(String::new())
+            }
+            "params" if self.params.is_empty() => {
+                Ok("No parameters have been changed from the puzzle's own".to_string())
+            }
+            "params" => Ok(toml::to_string(&self.params)?),
+            _ => bail!(
+                "Unknown command {:?}. Type help to list the commands",
+                command
+            ),
+        }
+    }
+}
+
+/// Runs each command read from `input` until it ends or says `quit`, writing what each prints to
+/// `output`. A command that fails prints its error without ending the session.
+pub fn run<R: BufRead, W: Write>(
+    session: &mut dyn Session,
+    mut input: R,
+    mut output: W,
+    prompt: bool,
+) -> Result<()> {
+    loop {
+        if prompt {
+            write!(output, "day{:02}> ", session.day())?;
+            output.flush()?;
+        }
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            break;
+        }
+        match line.trim() {
+            "" => continue,
+            "quit" | "exit" => break,
+            line => match session.eval(line) {
+                Ok(reply) if reply.is_empty() => {}
+                Ok(reply) => writeln!(output, "{}", reply.trim_end())?,
+                Err(error) => writeln!(output, "Error: {:#}", error)?,
+            },
+        }
+    }
+    Ok(())
+}
+
+/// Lists `(name, value)` pairs one per line, sorted by name.
+fn list<N: AsRef<str>, V: AsRef<str>>(mut entries: Vec<(N, V)>) -> String {
+    entries.sort_by(|(a, _), (b, _)| a.as_ref().cmp(b.as_ref()));
+    entries
+        .iter()
+        .map(|(name, value)| format!("{}: {}", name.as_ref(), value.as_ref()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::Path;
+
+    use aoc_common::read_input;
+
+    use crate::days;
+
+    fn start_session(day: u8, path: &str) -> Box<dyn Session> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let input = read_input(root.join(path)).unwrap();
+        (days::find(day).unwrap().repl)(&input, Table::new())
+            .unwrap()
+            .0
+    }
+
+    fn eval(session: &mut Box<dyn Session>, line: &str) -> String {
+        session
+            .eval(line)
+            .unwrap_or_else(|error| format!("{:#}", error))
+    }
+
+    #[test]
+    fn solves_parts_with_params() {
+        let mut session = start_session(9, "day09/input/test.txt");
+        assert!(eval(&mut session, "part1 preamble=5").starts_with("Part 1: 127\n"));
+        assert_eq!(
+            eval(&mut session, "params"),
+            "No parameters have been changed from the puzzle's own"
+        );
+        assert_eq!(eval(&mut session, "set day09.preamble=5"), "");
+        assert_eq!(eval(&mut session, "params"), "preamble = 5\n");
+        assert!(eval(&mut session, "part2").starts_with("Part 2: 62\n"));
+        assert!(eval(&mut session, "set preambel=5")
+            .starts_with("Invalid parameters for day 9: unknown field `preambel`"));
+        assert_eq!(
+            eval(&mut session, "part1 day10.preamble=5"),
+            "Only day 9's parameters can be set"
+        );
+    }
+
+    #[test]
+    fn shows_views() {
+        let mut session = start_session(7, "day07/input/test1.txt");
+        assert_eq!(
+            eval(&mut session, "show contents shiny gold"),
+            "1 dark olive bag, 2 vibrant plum bags"
+        );
+        assert_eq!(
+            eval(&mut session, "show containers shiny gold"),
+            "bright white, muted yellow"
+        );
+        assert!(eval(&mut session, "show contents")
+            .starts_with("bright white: 1 shiny gold bag\ndark olive: 3 faded blue bags"));
+
+        let mut session = start_session(16, "day16/input/test2.txt");
+        assert_eq!(
+            eval(&mut session, "show validations seat"),
+            "4 valid tickets\nfits every ticket at [2] (tickets fitting each position: 0: 3, 1: 3, \
+             2: 4)"
+        );
+        assert_eq!(
+            eval(&mut session, "show positions"),
+            "row: 0\nclass: 1\nseat: 2"
+        );
+
+        let mut session = start_session(21, "day21/input/test.txt");
+        assert_eq!(eval(&mut session, "show counts mxmxvkd"), "3");
+        assert_eq!(
+            eval(&mut session, "show allergens"),
+            "dairy: mxmxvkd\nfish: mxmxvkd, sqjhc\nsoy: fvjkl, sqjhc"
+        );
+    }
+
+    #[test]
+    fn reports_unknown_commands() {
+        let mut session = start_session(7, "day07/input/test1.txt");
+        assert_eq!(
+            eval(&mut session, "shwo contents"),
+            "Unknown command \"shwo\". Type help to list the commands"
+        );
+        assert_eq!(
+            eval(&mut session, "show bags"),
+            "There is no view named \"bags\". Type help to list day 7's views"
+        );
+        assert!(eval(&mut session, "help").contains("\n  containers [color]"));
+    }
+
+    #[test]
+    fn runs_commands() {
+        let mut session = start_session(1, "day01/input/test.txt");
+        let mut output = Vec::new();
+        run(
+            session.as_mut(),
+            "\nhelp\nset target=9\nfoo\nquit\npart1\n".as_bytes(),
+            &mut output,
+            false,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("Commands:\n"));
+        assert!(output.contains("Day 1 has no views yet.\n"));
+        assert!(
+            output.ends_with("Error: Unknown command \"foo\". Type help to list the commands\n")
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use anyhow::{bail, Result};
+use day07::Rules;
+
+use super::{list, View};
+
+pub fn views() -> Vec<View<Rules>> {
+    vec![
+        View {
+            name: "contents",
+            args: "[color]",
+            about: "The bags each bag (or just this one) must directly contain",
+            show: contents,
+        },
+        View {
+            name: "containers",
+            args: "[color]",
+            about: "The bags that can directly contain each bag (or just this one)",
+            show: containers,
+        },
+    ]
+}
+
+fn contents(rules: &Rules, color: &str) -> Result<String> {
+    let format = |bags: &Vec<(u32, String)>| {
+        if bags.is_empty() {
+            return "no other bags".to_string();
+        }
+        bags.iter()
+            .map(|(quantity, color)| {
+                format!(
+                    "{} {} bag{}",
+                    quantity,
+                    color,
+                    if *quantity == 1 { "" } else { "s" }
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    if color.is_empty() {
+        return Ok(list(
+            rules
+                .contents
+                .iter()
+                .map(|(color, bags)| (color, format(bags)))
+                .collect(),
+        ));
+    }
+    match rules.contents.get(color) {
+        Some(bags) => Ok(format(bags)),
+        None => bail!("There is no rule for {} bags", color),
+    }
+}
+
+fn containers(rules: &Rules, color: &str) -> Result<String> {
+    let format = |bags: Option<&_>| {
+        let mut bags: Vec<&String> = bags.into_iter().flatten().collect();
+        bags.sort();
+        match bags.is_empty() {
+            true => "no other bags".to_string(),
+            false => bags
+                .iter()
+                .map(|bag| bag.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    };
+    if color.is_empty() {
+        return Ok(list(
+            rules
+                .contents
+                .keys()
+                .map(|color| (color, format(rules.containers.get(color))))
+                .collect(),
+        ));
+    }
+    if !rules.contents.contains_key(color) {
+        bail!("There is no rule for {} bags", color);
+    }
+    Ok(format(rules.containers.get(color)))
+}
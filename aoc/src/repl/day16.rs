@@ -0,0 +1,118 @@
+use anyhow::{bail, Result};
+use day16::{map_fields, rule_validations, validate_ticket, Notes};
+
+use super::{list, View};
+
+pub fn views() -> Vec<View<Notes>> {
+    vec![
+        View {
+            name: "rules",
+            args: "",
+            about: "The rule for each field",
+            show: rules,
+        },
+        View {
+            name: "invalid",
+            args: "",
+            about: "The nearby tickets with a number no rule allows",
+            show: invalid,
+        },
+        View {
+            name: "validations",
+            args: "[field]",
+            about: "How many valid tickets allow each field at each position",
+            show: validations,
+        },
+        View {
+            name: "positions",
+            args: "",
+            about: "The position worked out for each field",
+            show: positions,
+        },
+    ]
+}
+
+fn format_ticket(ticket: &[usize]) -> String {
+    ticket
+        .iter()
+        .map(|num| num.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn rules(notes: &Notes, _: &str) -> Result<String> {
+    Ok(notes
+        .rules
+        .iter()
+        .map(|rule| rule.to_string())
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+fn invalid(notes: &Notes, _: &str) -> Result<String> {
+    let invalid: Vec<String> = notes
+        .nearby_tickets
+        .iter()
+        .filter(|ticket| !validate_ticket(&notes.rules, ticket))
+        .map(|ticket| format_ticket(ticket))
+        .collect();
+    Ok(format!(
+        "{} of {} nearby tickets are invalid\n{}",
+        invalid.len(),
+        notes.nearby_tickets.len(),
+        invalid.join("\n")
+    ))
+}
+
+fn validations(notes: &Notes, field: &str) -> Result<String> {
+    let validations = rule_validations(notes);
+    let format = |field: &str| {
+        let mut counts: Vec<_> = validations
+            .counts
+            .get(field)
+            .into_iter()
+            .flatten()
+            .collect();
+        counts.sort_unstable();
+        let counts: Vec<_> = counts
+            .iter()
+            .map(|(position, count)| format!("{}: {}", position, count))
+            .collect();
+        format!(
+            "fits every ticket at {:?} (tickets fitting each position: {})",
+            validations.possible_positions(field),
+            counts.join(", ")
+        )
+    };
+    let summary = format!("{} valid tickets", validations.valid_tickets);
+    if field.is_empty() {
+        let fields = notes
+            .rules
+            .iter()
+            .map(|rule| (rule.field.as_str(), format(&rule.field)))
+            .collect();
+        return Ok(format!("{}\n{}", summary, list(fields)));
+    }
+    if !notes.rules.iter().any(|rule| rule.field == field) {
+        bail!("There is no field named {:?}", field);
+    }
+    Ok(format!("{}\n{}", summary, format(field)))
+}
+
+fn positions(notes: &Notes, _: &str) -> Result<String> {
+    let fields = map_fields(notes);
+    let mut lines: Vec<_> = notes
+        .rules
+        .iter()
+        .map(|rule| match fields.get(rule.field.as_str()) {
+            Some(position) => (rule.field.as_str(), position.to_string()),
+            None => (rule.field.as_str(), "unknown".to_string()),
+        })
+        .collect();
+    lines.sort_by_key(|(_, position)| position.parse::<usize>().unwrap_or(usize::MAX));
+    Ok(lines
+        .iter()
+        .map(|(field, position)| format!("{}: {}", field, position))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+use day21::Foods;
+
+use super::{list, View};
+
+pub fn views() -> Vec<View<Foods>> {
+    vec![
+        View {
+            name: "counts",
+            args: "[ingredient]",
+            about: "How many foods each ingredient (or just this one) appears in",
+            show: counts,
+        },
+        View {
+            name: "allergens",
+            args: "[allergen]",
+            about: "The ingredients that could contain each allergen (or just this one)",
+            show: allergens,
+        },
+    ]
+}
+
+fn counts(foods: &Foods, ingredient: &str) -> Result<String> {
+    if ingredient.is_empty() {
+        return Ok(list(
+            foods
+                .ingredient_counts
+                .iter()
+                .map(|(ingredient, count)| (ingredient, count.to_string()))
+                .collect(),
+        ));
+    }
+    match foods.ingredient_counts.get(ingredient) {
+        Some(count) => Ok(count.to_string()),
+        None => bail!("No food has {}", ingredient),
+    }
+}
+
+fn allergens(foods: &Foods, allergen: &str) -> Result<String> {
+    let format = |ingredients: &HashSet<String>| {
+        let mut ingredients: Vec<&str> = ingredients.iter().map(String::as_str).collect();
+        ingredients.sort_unstable();
+        ingredients.join(", ")
+    };
+    if allergen.is_empty() {
+        return Ok(list(
+            foods
+                .allergen_ingredients
+                .iter()
+                .map(|(allergen, ingredients)| (allergen, format(ingredients)))
+                .collect(),
+        ));
+    }
+    match foods.allergen_ingredients.get(allergen) {
+        Some(ingredients) => Ok(format(ingredients)),
+        None => bail!("No food lists {}", allergen),
+    }
+}
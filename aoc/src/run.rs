@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use anyhow::anyhow;
+use aoc_common::{print_report, InputSource, Report};
+use toml::Table;
+
+use crate::days::Day;
+
+/// Runs the requested parts of a day against the given input with the given parameters. If the
+/// input can't be read, it is reported as a parse failure.
+pub fn run_day(day: &Day, source: &InputSource, parts: &[u8], params: &Table) -> Report {
+    match source.read() {
+        Ok(input) => (day.solve)(&input, parts, params),
+        Err(error) => Report {
+            day: day.number,
+            parse_elapsed: Duration::default(),
+            parse_error: Some(anyhow!("{:#}", error)),
+            parts: Vec::new(),
+        },
+    }
+}
+
+pub fn print_day(report: &Report) {
+    println!("Day {}", report.day);
+    print_report(report);
+    println!();
+}
+
+/// One row of the summary table.
+struct Row {
+    day: u8,
+    part: String,
+    answer: String,
+    parse: String,
+    solve: String,
+}
+
+/// Formats the reports of a run as a table with totals of the parse and solve times.
+pub fn format_summary(reports: &[Report]) -> String {
+    let mut rows = Vec::new();
+    for report in reports {
+        let parse = format!("{:?}", report.parse_elapsed);
+        if report.parse_error.is_some() {
+            rows.push(Row {
+                day: report.day,
+                part: "-".to_string(),
+                answer: "(failed to parse)".to_string(),
+                parse: parse.clone(),
+                solve: "-".to_string(),
+            });
+        }
+        for part in &report.parts {
+            rows.push(Row {
+                day: report.day,
+                part: part.part.to_string(),
+                answer: match &part.answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(_) => "(failed)".to_string(),
+                },
+                parse: parse.clone(),
+                solve: format!("{:?}", part.elapsed),
+            });
+        }
+    }
+    let parse_total: Duration = reports.iter().map(|report| report.parse_elapsed).sum();
+    let solve_total: Duration = reports
+        .iter()
+        .flat_map(|report| report.parts.iter().map(|part| part.elapsed))
+        .sum();
+    let parse_total = format!("{:?}", parse_total);
+
+    let answer_width = rows
+        .iter()
+        .map(|row| row.answer.len())
+        .chain(std::iter::once("Answer".len()))
+        .max()
+        .expect("non-empty iterator");
+    let parse_width = rows
+        .iter()
+        .map(|row| row.parse.len())
+        .chain(vec!["Parse".len(), parse_total.len()])
+        .max()
+        .expect("non-empty iterator");
+
+    let mut summary = format!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:<parse_width$}  {}\n",
+        "Day",
+        "Part",
+        "Answer",
+        "Parse",
+        "Solve",
+        answer_width = answer_width,
+        parse_width = parse_width,
+    );
+    for row in rows {
+        summary.push_str(&format!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:<parse_width$}  {}\n",
+            row.day,
+            row.part,
+            row.answer,
+            row.parse,
+            row.solve,
+            answer_width = answer_width,
+            parse_width = parse_width,
+        ));
+    }
+    summary.push_str(&format!(
+        "{:<width$}  {:<parse_width$}  {:?}\n",
+        "Total",
+        parse_total,
+        solve_total,
+        width = 3 + 2 + 4 + 2 + answer_width,
+        parse_width = parse_width,
+    ));
+    summary
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::{Answer, PartReport};
+
+    #[test]
+    fn formats_summary() {
+        let reports = vec![
+            Report {
+                day: 1,
+                parse_elapsed: Duration::from_millis(1),
+                parse_error: None,
+                parts: vec![
+                    PartReport {
+                        part: 1,
+                        answer: Ok(Answer::Integer(514579)),
+                        elapsed: Duration::from_millis(1),
+                    },
+                    PartReport {
+                        part: 2,
+                        answer: Err(anyhow!("no answer")),
+                        elapsed: Duration::from_millis(2),
+                    },
+                ],
+            },
+            Report {
+                day: 21,
+                parse_elapsed: Duration::from_millis(3),
+                parse_error: Some(anyhow!("Unexpected end of input")),
+                parts: vec![],
+            },
+        ];
+        assert_eq!(
+            format_summary(&reports),
+            "Day  Part  Answer             Parse  Solve\n  \
+             1     1  514579             1ms    1ms\n  \
+             1     2  (failed)           1ms    2ms\n \
+             21     -  (failed to parse)  3ms    -\n\
+             Total                         4ms    3ms\n"
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const ANSWERS_TOML: &str = include_str!("../templates/answers.toml.tmpl");
+
+/// Every file a new day is registered in, relative to the workspace root, and how the lines
+/// registering each day start.
+const REGISTRIES: [(&str, &[&str]); 4] = [
+    ("Cargo.toml", &["\"day"]),
+    ("aoc/Cargo.toml", &["day"]),
+    ("aoc/src/days.rs", &["day!(day"]),
+    (
+        "aoc/benches/days.rs",
+        &["bench_day::<day", "bench_slow_day::<day"],
+    ),
+];
+
+fn render(template: &str, number: u8) -> String {
+    template
+        .replace("{{name}}", &format!("day{:02}", number))
+        .replace("{{type}}", &format!("Day{:02}", number))
+        .replace("{{number}}", &number.to_string())
+}
+
+/// The day number in a line registering a day, if `prefix` is followed by one, like `day07`.
+fn registered_day(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.trim_start().strip_prefix(prefix)?;
+    rest.get(..2)?.parse().ok()
+}
+
+/// Inserts `new_line` among the lines registering each day, keeping them ordered by day.
+fn register(text: &str, number: u8, new_line: &str, prefixes: &[&str]) -> Result<String> {
+    let day_of = |line: &str| {
+        prefixes
+            .iter()
+            .find_map(|prefix| registered_day(line, prefix))
+    };
+    let lines: Vec<&str> = text.lines().collect();
+    if lines.iter().any(|line| day_of(line) == Some(number)) {
+        bail!("Day {} is already registered", number);
+    }
+    let index = match lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|day| day > number))
+    {
+        Some(index) => index,
+        None => {
+            lines
+                .iter()
+                .rposition(|line| day_of(line).is_some())
+                .ok_or_else(|| anyhow!("Found nowhere to register day {}", number))?
+                + 1
+        }
+    };
+    let mut registered: Vec<&str> = lines[..index].to_vec();
+    registered.push(new_line);
+    registered.extend(&lines[index..]);
+    Ok(registered.join("\n") + "\n")
+}
+
+fn register_file(path: &Path, number: u8, new_line: &str, prefixes: &[&str]) -> Result<()> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let text = register(&text, number, new_line, prefixes)
+        .with_context(|| format!("Failed to register day in {}", path.display()))?;
+    fs::write(path, text).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Creates the crate for a new day under `root` with stub solutions, and registers it with the
+/// workspace, the runner and the benchmarks. Returns every file created or changed.
+pub fn new_day(root: &Path, number: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&number) {
+        bail!("Expected a day from 1 to 25, got: {}", number);
+    }
+    let name = format!("day{:02}", number);
+    let day_type = format!("Day{:02}", number);
+    let day_dir = root.join(&name);
+    if day_dir.exists() {
+        bail!("{} already exists", day_dir.display());
+    }
+
+    let files = [
+        (day_dir.join("Cargo.toml"), render(CARGO_TOML, number)),
+        (day_dir.join("answers.toml"), render(ANSWERS_TOML, number)),
+        (day_dir.join("input").join("input.txt"), String::new()),
+        (day_dir.join("input").join("test.txt"), String::new()),
+        (day_dir.join("src").join("lib.rs"), render(LIB_RS, number)),
+        (day_dir.join("src").join("main.rs"), render(MAIN_RS, number)),
+    ];
+    fs::create_dir_all(day_dir.join("input"))?;
+    fs::create_dir_all(day_dir.join("src"))?;
+    for (path, contents) in files.iter() {
+        fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    let new_lines = [
+        format!("    \"{}\",", name),
+        format!("{} = {{ path = \"../{}\" }}", name, name),
+        format!("        day!({}::{}),", name, day_type),
+        format!("    bench_day::<{}::{}>,", name, day_type),
+    ];
+    for ((file, prefixes), new_line) in REGISTRIES.iter().zip(new_lines.iter()) {
+        register_file(&root.join(file), number, new_line, prefixes)?;
+    }
+
+    Ok(files
+        .iter()
+        .map(|(path, _)| path.clone())
+        .chain(REGISTRIES.iter().map(|(file, _)| root.join(file)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_templates() {
+        let lib = render(LIB_RS, 20);
+        assert!(lib.starts_with("//! Day 20.\n"));
+        assert!(lib.contains("pub struct Day20;"));
+        assert!(lib.contains("    const DAY: u8 = 20;"));
+        assert!(!lib.contains("{{"));
+        assert!(render(MAIN_RS, 5).contains("use day05::Day05;"));
+        assert!(render(CARGO_TOML, 5).contains("name = \"day05\""));
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        let members = "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day21\",\n]\n";
+        assert_eq!(
+            register(members, 20, "    \"day20\",", &["\"day"]).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day20\",\n    \"day21\",\n]\n"
+        );
+        assert_eq!(
+            register(members, 25, "    \"day25\",", &["\"day"]).unwrap(),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n    \"day21\",\n    \"day25\",\n]\n"
+        );
+        assert!(register(members, 21, "    \"day21\",", &["\"day"]).is_err());
+
+        let benches = "    bench_day::<day19::Day19>,\n    bench_slow_day::<day23::Day23>,\n)";
+        assert_eq!(
+            register(
+                benches,
+                22,
+                "    bench_day::<day22::Day22>,",
+                &["bench_day::<day", "bench_slow_day::<day"]
+            )
+            .unwrap(),
+            "    bench_day::<day19::Day19>,\n    bench_day::<day22::Day22>,\n    bench_slow_day::<day23::Day23>,\n)\n"
+        );
+    }
+
+    #[test]
+    fn finds_registered_days() {
+        let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for (file, prefixes) in REGISTRIES.iter() {
+            let text = fs::read_to_string(workspace.join(file)).unwrap();
+            let days: Vec<u8> = text
+                .lines()
+                .filter_map(|line| {
+                    prefixes
+                        .iter()
+                        .find_map(|prefix| registered_day(line, prefix))
+                })
+                .collect();
+            assert_eq!(days[0], 1, "day 1 is not registered in {}", file);
+            assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn creates_days() {
+        let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+        let fixtures = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\n    \"aoc\",\n    \"day01\",\n]\n",
+            ),
+            (
+                "aoc/Cargo.toml",
+                "[dependencies]\nday01 = { path = \"../day01\" }\n",
+            ),
+            (
+                "aoc/src/days.rs",
+                "    vec![\n        day!(day01::Day01),\n    ]\n",
+            ),
+            ("aoc/benches/days.rs", "    bench_day::<day01::Day01>,\n"),
+        ];
+        for (file, contents) in fixtures.iter() {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+
+        let files = new_day(&root, 24).unwrap();
+        assert_eq!(files.len(), 10);
+        assert!(root.join("day24/src/lib.rs").exists());
+        assert!(root.join("day24/input/test.txt").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/src/days.rs")).unwrap(),
+            "    vec![\n        day!(day01::Day01),\n        day!(day24::Day24),\n    ]\n"
+        );
+        assert!(new_day(&root, 24).is_err());
+        assert!(new_day(&root, 26).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
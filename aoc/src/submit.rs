@@ -0,0 +1,492 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::client::{Client, YEAR};
+
+/// Whether a submitted answer was right, and if not, which way it was off when the site says.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "right"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    /// The answer was checked. After a wrong answer the site won't check another for a while.
+    Answered {
+        verdict: Verdict,
+        wait: Option<Duration>,
+    },
+    /// An answer was submitted too recently, so this one wasn't checked.
+    TooSoon(Duration),
+    /// The part is already solved, or it is part 2 and part 1 isn't.
+    WrongLevel,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answered {
+                verdict: Verdict::Correct,
+                ..
+            } => write!(f, "That's the right answer!")?,
+            Outcome::Answered {
+                verdict: Verdict::Wrong,
+                ..
+            } => write!(f, "That's not the right answer.")?,
+            Outcome::Answered { verdict, .. } => {
+                write!(f, "That's not the right answer, it's {}.", verdict)?
+            }
+            Outcome::TooSoon(_) => write!(f, "An answer was submitted too recently.")?,
+            Outcome::WrongLevel => {
+                return write!(
+                    f,
+                    "That part is already solved, or part 1 isn't solved yet."
+                )
+            }
+        }
+        match self {
+            Outcome::Answered {
+                wait: Some(wait), ..
+            }
+            | Outcome::TooSoon(wait) => {
+                write!(f, " Wait {} before trying again.", format_wait(*wait))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Formats a wait the way the site does, like `4m 12s`.
+pub fn format_wait(wait: Duration) -> String {
+    let seconds = wait.as_secs() + u64::from(wait.subsec_nanos() > 0);
+    match seconds / 60 {
+        0 => format!("{}s", seconds),
+        minutes if seconds.is_multiple_of(60) => format!("{}m", minutes),
+        minutes => format!("{}m {}s", minutes, seconds % 60),
+    }
+}
+
+/// Reads what the site said from the page it responds to an answer with.
+pub fn parse_outcome(page: &str) -> Result<Outcome> {
+    let text = strip_tags(page);
+    if text.contains("That's the right answer") {
+        Ok(Outcome::Answered {
+            verdict: Verdict::Correct,
+            wait: None,
+        })
+    } else if text.contains("That's not the right answer") {
+        let verdict = if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        };
+        Ok(Outcome::Answered {
+            verdict,
+            wait: parse_wait(&text),
+        })
+    } else if text.contains("You gave an answer too recently") {
+        Ok(Outcome::TooSoon(
+            parse_wait(&text).unwrap_or(Duration::from_secs(60)),
+        ))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Outcome::WrongLevel)
+    } else {
+        bail!("Unexpected response to the answer: {}", text.trim())
+    }
+}
+
+fn strip_tags(page: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in page.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Finds how long the site says to wait, either as `You have 1m 5s left to wait` or as `Please wait
+/// one minute before trying again`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some(end) = text.find(" left to wait") {
+        let start = text[..end].rfind("You have ")? + "You have ".len();
+        return text[start..end]
+            .split_whitespace()
+            .map(|amount| {
+                let (number, unit) = amount.split_at(amount.find(|c: char| c.is_alphabetic())?);
+                let number: u64 = number.parse().ok()?;
+                match unit {
+                    "h" => Some(number * 60 * 60),
+                    "m" => Some(number * 60),
+                    "s" => Some(number),
+                    _ => None,
+                }
+            })
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+    let start = text.find("wait ")? + "wait ".len();
+    let mut words = text[start..].split_whitespace();
+    let number = match words.next()? {
+        "one" | "a" => 1,
+        number => number.parse().ok()?,
+    };
+    match words.next()?.trim_end_matches('s') {
+        "second" => Some(Duration::from_secs(number)),
+        "minute" => Some(Duration::from_secs(number * 60)),
+        _ => None,
+    }
+}
+
+/// An answer submitted for one part.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far and when the site will check another, so that no answer that is
+/// known to be wrong is submitted again and waits the site asks for are kept to.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct History {
+    /// When the site will check another answer, in seconds since the Unix epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_answer: Option<u64>,
+    #[serde(rename = "guess")]
+    guesses: Vec<Guess>,
+}
+
+impl History {
+    /// Loads the history file at `path`, or an empty history if there isn't one.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let s = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read history file: {}", path.display()))?;
+        toml::from_str(&s)
+            .with_context(|| format!("Failed to parse history file: {}", path.display()))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Failed to write history file: {}", path.display()))
+    }
+
+    /// Fails if `answer` can't be right given the earlier guesses for the part, or if the site
+    /// won't check an answer yet.
+    pub fn check(&self, day: u8, part: u8, answer: &str, now: SystemTime) -> Result<()> {
+        let guesses: Vec<_> = self
+            .guesses
+            .iter()
+            .filter(|guess| guess.day == day && guess.part == part)
+            .collect();
+        if let Some(guess) = guesses
+            .iter()
+            .find(|guess| guess.verdict == Verdict::Correct)
+        {
+            bail!(
+                "Day {} part {} was already solved with {}",
+                day,
+                part,
+                guess.answer
+            );
+        }
+        if let Some(guess) = guesses.iter().find(|guess| guess.answer == answer) {
+            bail!(
+                "{} was already {} for day {} part {}",
+                answer,
+                guess.verdict,
+                day,
+                part
+            );
+        }
+        if let Ok(number) = answer.parse::<i128>() {
+            for guess in &guesses {
+                let bound = match guess.answer.parse::<i128>() {
+                    Ok(bound) => bound,
+                    Err(_) => continue,
+                };
+                if (guess.verdict == Verdict::TooHigh && number >= bound)
+                    || (guess.verdict == Verdict::TooLow && number <= bound)
+                {
+                    bail!(
+                        "{} can't be right for day {} part {}, {} was already {}",
+                        answer,
+                        day,
+                        part,
+                        guess.answer,
+                        guess.verdict
+                    );
+                }
+            }
+        }
+        if let Some(next) = self.next_answer {
+            let wait = Duration::from_secs(next).saturating_sub(seconds(now));
+            if !wait.is_zero() {
+                bail!(
+                    "The site won't check another answer yet. Wait {} before trying again",
+                    format_wait(wait)
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Records what the site said about `answer`.
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, outcome: &Outcome, now: SystemTime) {
+        let wait = match *outcome {
+            Outcome::Answered { verdict, wait } => {
+                self.guesses.push(Guess {
+                    day,
+                    part,
+                    answer: answer.to_owned(),
+                    verdict,
+                });
+                wait
+            }
+            Outcome::TooSoon(wait) => Some(wait),
+            Outcome::WrongLevel => None,
+        };
+        self.next_answer = wait.map(|wait| (seconds(now) + wait).as_secs());
+    }
+}
+
+/// The time since the Unix epoch, to the second.
+fn seconds(time: SystemTime) -> Duration {
+    Duration::from_secs(
+        time.duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+    )
+}
+
+/// Submits `answer` for a part unless the history shows it can't be right or the site won't check
+/// it yet, and records what the site said.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome> {
+    let now = SystemTime::now();
+    history.check(day, part, answer, now)?;
+    let page = client
+        .post(
+            &format!("/{}/day/{}/answer", YEAR, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )
+        .with_context(|| format!("Failed to submit the answer to day {} part {}", day, part))?;
+    let outcome = parse_outcome(&page)?;
+    history.record(day, part, answer, &outcome, now);
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    use crate::mock_server::MockServer;
+
+    fn workspace() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("..")
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            parse_outcome("<article><p>That's the right answer!  You are one gold star closer to saving your vacation. <a href=\"/2020\">[Return]</a></p></article>").unwrap(),
+            Outcome::Answered { verdict: Verdict::Correct, wait: None }
+        );
+        assert_eq!(
+            parse_outcome("<article><p>That's not the right answer; your answer is too low.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.</p></article>").unwrap(),
+            Outcome::Answered { verdict: Verdict::TooLow, wait: Some(Duration::from_secs(60)) }
+        );
+        assert_eq!(
+            parse_outcome(
+                "<p>That's not the right answer.  Please wait 5 minutes before trying again.</p>"
+            )
+            .unwrap(),
+            Outcome::Answered {
+                verdict: Verdict::Wrong,
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            parse_outcome("<p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.</p>").unwrap(),
+            Outcome::TooSoon(Duration::from_secs(65))
+        );
+        assert_eq!(
+            parse_outcome("<p>You don't seem to be solving the right level.  Did you already complete it?</p>").unwrap(),
+            Outcome::WrongLevel
+        );
+        assert!(parse_outcome("<p>Something else</p>").is_err());
+    }
+
+    #[test]
+    fn formats_outcomes() {
+        let outcome = Outcome::Answered {
+            verdict: Verdict::TooHigh,
+            wait: Some(Duration::from_secs(60)),
+        };
+        assert_eq!(
+            outcome.to_string(),
+            "That's not the right answer, it's too high. Wait 1m before trying again."
+        );
+        assert_eq!(
+            Outcome::TooSoon(Duration::from_millis(44_500)).to_string(),
+            "An answer was submitted too recently. Wait 45s before trying again."
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+        let later = now + Duration::from_secs(60);
+        let mut history = History::default();
+        let wrong = |verdict| Outcome::Answered {
+            verdict,
+            wait: Some(Duration::from_secs(60)),
+        };
+        history.record(1, 1, "500", &wrong(Verdict::TooHigh), now);
+        history.record(1, 1, "100", &wrong(Verdict::TooLow), now);
+        history.record(1, 1, "abc", &wrong(Verdict::Wrong), now);
+
+        let error = |answer| format!("{}", history.check(1, 1, answer, later).unwrap_err());
+        assert_eq!(error("abc"), "abc was already wrong for day 1 part 1");
+        assert_eq!(error("500"), "500 was already too high for day 1 part 1");
+        assert_eq!(
+            error("600"),
+            "600 can't be right for day 1 part 1, 500 was already too high"
+        );
+        assert_eq!(
+            error("-3"),
+            "-3 can't be right for day 1 part 1, 100 was already too low"
+        );
+        history.check(1, 1, "300", later).unwrap();
+        history.check(1, 2, "500", later).unwrap();
+        assert_eq!(
+            format!("{}", history.check(2, 1, "1", now).unwrap_err()),
+            "The site won't check another answer yet. Wait 1m before trying again"
+        );
+
+        let correct = Outcome::Answered {
+            verdict: Verdict::Correct,
+            wait: None,
+        };
+        history.record(1, 1, "300", &correct, later);
+        assert_eq!(
+            format!("{}", history.check(1, 1, "301", later).unwrap_err()),
+            "Day 1 part 1 was already solved with 300"
+        );
+    }
+
+    #[test]
+    fn saves_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("history.toml");
+        let mut history = History::load(&path).unwrap();
+        let outcome = Outcome::Answered {
+            verdict: Verdict::TooLow,
+            wait: Some(Duration::from_secs(60)),
+        };
+        history.record(7, 2, "12", &outcome, UNIX_EPOCH);
+        history.save(&path).unwrap();
+        let history = History::load(&path).unwrap();
+        assert_eq!(
+            history.guesses,
+            vec![Guess {
+                day: 7,
+                part: 2,
+                answer: "12".to_string(),
+                verdict: Verdict::TooLow,
+            }]
+        );
+        assert_eq!(history.next_answer, Some(60));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let server = MockServer::start(&workspace(), "secret", 0).unwrap();
+        let client = Client::new(server.url(), "secret");
+        let mut history = History::default();
+        let correct = Outcome::Answered {
+            verdict: Verdict::Correct,
+            wait: None,
+        };
+        assert_eq!(
+            submit(&client, &mut history, 1, 2, "241861950").unwrap(),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            submit(&client, &mut history, 1, 1, "514579").unwrap(),
+            correct
+        );
+        assert_eq!(
+            submit(&client, &mut history, 1, 2, "241861950").unwrap(),
+            correct
+        );
+        assert_eq!(
+            submit(&client, &mut History::default(), 1, 2, "241861950").unwrap(),
+            Outcome::WrongLevel
+        );
+        assert!(submit(&client, &mut history, 1, 1, "514579").is_err());
+    }
+
+    #[test]
+    fn submits_wrong_answers() {
+        let server = MockServer::start(&workspace(), "secret", 0).unwrap();
+        let client = Client::new(server.url(), "secret");
+        let mut history = History::default();
+        assert_eq!(
+            submit(&client, &mut history, 1, 1, "600000").unwrap(),
+            Outcome::Answered {
+                verdict: Verdict::TooHigh,
+                wait: Some(Duration::from_secs(60)),
+            }
+        );
+        // The history refuses answers without asking the site.
+        assert!(submit(&client, &mut history, 1, 1, "700000").is_err());
+        assert!(submit(&client, &mut history, 2, 1, "2").is_err());
+        // The site keeps its own time.
+        assert!(matches!(
+            submit(&client, &mut History::default(), 1, 1, "514579").unwrap(),
+            Outcome::TooSoon(wait) if wait <= Duration::from_secs(60)
+        ));
+    }
+}
@@ -0,0 +1,225 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, bail, Context, Result};
+use aoc_common::{read_input, Answer, Report};
+use serde::Deserialize;
+use toml::Table;
+
+use crate::days::Day;
+
+/// The file in each day's directory recording the expected answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// An expected answer as written in an answers file, either a bare integer or a string.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Integer(i64),
+    Text(String),
+}
+
+impl From<Expected> for Answer {
+    fn from(expected: Expected) -> Self {
+        match expected {
+            Expected::Integer(answer) => answer.into(),
+            Expected::Text(answer) => answer.into(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ExpectedInput {
+    path: PathBuf,
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnswersFile {
+    #[serde(rename = "input")]
+    inputs: Vec<ExpectedInput>,
+}
+
+/// The answers expected for each part of one input. Parts without a recorded answer are skipped.
+#[derive(Debug, PartialEq)]
+pub struct ExpectedAnswers {
+    pub path: PathBuf,
+    pub parts: Vec<(u8, Answer)>,
+}
+
+pub fn parse_answers(s: &str) -> Result<Vec<ExpectedAnswers>> {
+    let file: AnswersFile = toml::from_str(s)?;
+    Ok(file
+        .inputs
+        .into_iter()
+        .map(|input| {
+            let parts = vec![(1, input.part1), (2, input.part2)]
+                .into_iter()
+                .filter_map(|(part, answer)| answer.map(|answer| (part, answer.into())))
+                .collect();
+            ExpectedAnswers {
+                path: input.path,
+                parts,
+            }
+        })
+        .collect())
+}
+
+pub fn load_answers<P: AsRef<Path>>(path: P) -> Result<Vec<ExpectedAnswers>> {
+    let path = path.as_ref();
+    let s = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read answers file: {}", path.display()))?;
+    parse_answers(&s).with_context(|| format!("Failed to parse answers file: {}", path.display()))
+}
+
+/// The result of checking one part of a day against one input.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub path: PathBuf,
+    pub part: u8,
+    pub expected: Answer,
+    pub actual: Result<Answer>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(&self.actual, Ok(actual) if *actual == self.expected)
+    }
+}
+
+impl std::fmt::Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} part {} ({}): ",
+            self.day,
+            self.part,
+            self.path.display()
+        )?;
+        match &self.actual {
+            Ok(actual) if *actual == self.expected => write!(f, "ok"),
+            Ok(actual) => write!(f, "expected {}, got {}", self.expected, actual),
+            Err(error) => write!(f, "expected {}, failed: {:#}", self.expected, error),
+        }
+    }
+}
+
+/// The answer a report gives for `part`, or why it has none.
+pub fn part_answer(report: &Report, part: u8) -> Result<Answer> {
+    if let Some(error) = &report.parse_error {
+        bail!("Failed to parse input: {:#}", error);
+    }
+    let result = report
+        .parts
+        .iter()
+        .find(|result| result.part == part)
+        .ok_or_else(|| anyhow!("Part {} was not run", part))?;
+    match &result.answer {
+        Ok(answer) => Ok(answer.clone()),
+        Err(error) => Err(anyhow!("{:#}", error)),
+    }
+}
+
+/// Solves each input listed in the day's answers file and checks every part with a recorded
+/// answer. The puzzle's own parameters are always used, since those are what the answers are for.
+pub fn verify_day(day: &Day, day_dir: &Path) -> Result<Vec<Check>> {
+    let mut checks = Vec::new();
+    for expected in load_answers(day_dir.join(ANSWERS_FILE))? {
+        let parts: Vec<u8> = expected.parts.iter().map(|&(part, _)| part).collect();
+        let report = read_input(day_dir.join(&expected.path))
+            .map(|input| (day.solve)(&input, &parts, &Table::new()))
+            .map_err(|error| anyhow!("{:#}", error));
+        for (part, answer) in expected.parts {
+            let actual = match &report {
+                Ok(report) => part_answer(report, part),
+                Err(error) => Err(anyhow!("{:#}", error)),
+            };
+            checks.push(Check {
+                day: day.number,
+                path: expected.path.clone(),
+                part,
+                expected: answer,
+                actual,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::days;
+
+    #[test]
+    fn parses_answers() {
+        let answers = parse_answers(
+            r#"
+            [[input]]
+            path = "input/test.txt"
+            part1 = 5
+
+            [[input]]
+            path = "input/input.txt"
+            part1 = 1882
+            part2 = "xgtj,ztdctgq"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            answers,
+            vec![
+                ExpectedAnswers {
+                    path: "input/test.txt".into(),
+                    parts: vec![(1, Answer::Integer(5))],
+                },
+                ExpectedAnswers {
+                    path: "input/input.txt".into(),
+                    parts: vec![
+                        (1, Answer::Integer(1882)),
+                        (2, Answer::Text("xgtj,ztdctgq".to_string()))
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_checks() {
+        let mut check = Check {
+            day: 1,
+            path: "input/test.txt".into(),
+            part: 2,
+            expected: Answer::Integer(241861950),
+            actual: Ok(Answer::Integer(241861950)),
+        };
+        assert!(check.passed());
+        assert_eq!(check.to_string(), "Day 1 part 2 (input/test.txt): ok");
+
+        check.actual = Ok(Answer::Integer(7));
+        assert!(!check.passed());
+        assert_eq!(
+            check.to_string(),
+            "Day 1 part 2 (input/test.txt): expected 241861950, got 7"
+        );
+
+        check.actual = Err(anyhow!("No solution"));
+        assert!(!check.passed());
+        assert_eq!(
+            check.to_string(),
+            "Day 1 part 2 (input/test.txt): expected 241861950, failed: No solution"
+        );
+    }
+
+    #[test]
+    fn every_day_has_answers() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        for day in days::all() {
+            let answers = load_answers(root.join(day.name()).join(ANSWERS_FILE)).unwrap();
+            assert!(!answers.is_empty(), "{} has no answers", day.name());
+        }
+    }
+}
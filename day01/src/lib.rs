@@ -0,0 +1,97 @@
+//! Day 1: Report Repair.
+
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse_lines, Answer, Solution};
+use serde::Deserialize;
+
+/// What the entries that need fixing sum to.
+pub const TARGET: i32 = 2020;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub target: i32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { target: TARGET }
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<i32>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        Ok(parse_lines(input, |line| Ok(line.parse()?))?)
+    }
+
+    fn part1(numbers: &Vec<i32>, params: &Params) -> Result<Answer> {
+        Ok(solve_part1(numbers, params.target)?.into())
+    }
+
+    fn part2(numbers: &Vec<i32>, params: &Params) -> Result<Answer> {
+        Ok(solve_part2(numbers, params.target)?.into())
+    }
+}
+
+pub fn solve_part1(numbers: &[i32], target: i32) -> Result<i32> {
+    let mut prev_numbers = HashSet::new();
+    for &number in numbers {
+        let other_half = target - number;
+        if prev_numbers.contains(&other_half) {
+            return Ok(number * other_half);
+        }
+        prev_numbers.insert(number);
+    }
+
+    Err(anyhow!("Found no pair of numbers that sums to {}", target))
+}
+
+pub fn solve_part2(numbers: &[i32], target: i32) -> Result<i32> {
+    let mut prev_numbers = HashSet::new();
+    for &number in numbers {
+        for prev_number in prev_numbers.iter() {
+            let other_third = target - prev_number - number;
+            if prev_numbers.contains(&other_third) {
+                return Ok(number * prev_number * other_third);
+            }
+        }
+        prev_numbers.insert(number);
+    }
+
+    Err(anyhow!("Found no three numbers that sum to {}", target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day01::parse_file(TEST_INPUT).unwrap(), TARGET).unwrap(),
+            514579
+        );
+        assert_eq!(
+            solve_part1(&Day01::parse_file(TEST_INPUT).unwrap(), 1345).unwrap(),
+            979 * 366
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day01::parse_file(TEST_INPUT).unwrap(), TARGET).unwrap(),
+            241861950
+        );
+    }
+}
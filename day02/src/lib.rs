@@ -0,0 +1,260 @@
+//! Day 2: Password Philosophy.
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+use aoc_common::{parse_lines, Answer, NoParams, Solution, TokenError};
+
+#[derive(Debug, PartialEq)]
+pub struct PasswordRule {
+    pub letter: char,
+    pub range: RangeInclusive<usize>,
+}
+
+impl FromStr for PasswordRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (range, letter) = s
+            .split_once(' ')
+            .ok_or_else(|| TokenError::missing(s, "expected a letter after the range"))?;
+        let (start, end) = range
+            .split_once('-')
+            .ok_or_else(|| TokenError::new(s, range, "expected a range like 1-3"))?;
+        let parse_position = |position: &str| match position.parse() {
+            Ok(position) if position > 0 => Ok(position),
+            _ => Err(TokenError::new(s, position, "expected a position from 1")),
+        };
+        let start = parse_position(start)?;
+        let end = parse_position(end)?;
+        if end < start {
+            return Err(
+                TokenError::new(s, range, "expected the range to end after it starts").into(),
+            );
+        }
+        let mut chars = letter.chars();
+        let letter = match (chars.next(), chars.next()) {
+            (Some(letter), None) => letter,
+            _ => return Err(TokenError::new(s, letter, "expected a single letter").into()),
+        };
+        Ok(Self {
+            letter,
+            range: start..=end,
+        })
+    }
+}
+
+impl fmt::Display for PasswordRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}",
+            self.range.start(),
+            self.range.end(),
+            self.letter
+        )
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct PasswordEntry {
+    pub rule: PasswordRule,
+    pub password: String,
+}
+
+impl FromStr for PasswordEntry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (rule, password) = s
+            .split_once(": ")
+            .ok_or_else(|| TokenError::missing(s, "expected \": \" before the password"))?;
+        Ok(Self {
+            rule: rule.parse()?,
+            password: password.to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for PasswordEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.rule, self.password)
+    }
+}
+
+impl PasswordEntry {
+    /// Checks that the letter occurs in the password a number of times within the range.
+    pub fn validate_occurences(&self) -> bool {
+        self.rule.range.contains(
+            &self
+                .password
+                .chars()
+                .filter(|&c| c == self.rule.letter)
+                .count(),
+        )
+    }
+
+    /// Checks that the letter is at exactly one of the two (1-indexed) positions given by the range.
+    pub fn validate_positions(&self) -> bool {
+        let letter_at = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|index| self.password.chars().nth(index))
+                == Some(self.rule.letter)
+        };
+        letter_at(*self.rule.range.start()) ^ letter_at(*self.rule.range.end())
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<PasswordEntry>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<PasswordEntry>> {
+        Ok(parse_lines(input, str::parse)?)
+    }
+
+    fn part1(entries: &Vec<PasswordEntry>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(entries)?.into())
+    }
+
+    fn part2(entries: &Vec<PasswordEntry>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(entries)?.into())
+    }
+}
+
+pub fn solve_part1(entries: &[PasswordEntry]) -> Result<usize> {
+    Ok(entries
+        .iter()
+        .filter(|entry| entry.validate_occurences())
+        .count())
+}
+
+pub fn solve_part2(entries: &[PasswordEntry]) -> Result<usize> {
+    Ok(entries
+        .iter()
+        .filter(|entry| entry.validate_positions())
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::ParseError;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let input = Day02::parse_file(TEST_INPUT).unwrap();
+        assert_eq!(
+            input[0],
+            PasswordEntry {
+                rule: PasswordRule {
+                    letter: 'a',
+                    range: 1..=3,
+                },
+                password: "abcde".to_string(),
+            }
+        );
+        assert_eq!(
+            input[1],
+            PasswordEntry {
+                rule: PasswordRule {
+                    letter: 'b',
+                    range: 1..=3,
+                },
+                password: "cdefg".to_string(),
+            }
+        );
+        assert_eq!(
+            input[2],
+            PasswordEntry {
+                rule: PasswordRule {
+                    letter: 'c',
+                    range: 2..=9,
+                },
+                password: "ccccccccc".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn reports_malformed_entries() {
+        let error = |input: &str| {
+            Day02::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("1-3 a: abcde\n1-x b: cdefg"),
+            ParseError::invalid(2, 3, "x", "expected a position from 1")
+        );
+        assert_eq!(
+            error("3-1 a: abcde"),
+            ParseError::invalid(1, 1, "3-1", "expected the range to end after it starts")
+        );
+        assert_eq!(
+            error("1-3 ab: abcde"),
+            ParseError::invalid(1, 5, "ab", "expected a single letter")
+        );
+        assert_eq!(
+            error("1-3 a abcde"),
+            ParseError::invalid(1, 12, "", "expected \": \" before the password")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_entries(
+            start in 1..100usize,
+            length in 0..100usize,
+            letter in "[a-z]",
+            password in "[a-z: ]*",
+        ) {
+            let entry = PasswordEntry {
+                rule: PasswordRule {
+                    letter: letter.chars().next().unwrap(),
+                    range: start..=start + length,
+                },
+                password,
+            };
+            prop_assert_eq!(entry.to_string().parse::<PasswordEntry>().unwrap(), entry);
+        }
+
+        #[test]
+        fn parses_any_line_without_panicking(
+            line in "[^\n]*|[0-9 -]{0,8}[a-z ]{0,3}(: ?)?[a-z: ]*"
+        ) {
+            if let Ok(entries) = Day02::parse(&line) {
+                for entry in entries {
+                    prop_assert_eq!(entry.to_string().parse::<PasswordEntry>().unwrap(), entry);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day02::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day02::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            1
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Day 3: Toboggan Trajectory.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{Answer, Grid2D, NoParams, Solution};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Cell {
+    Tree,
+    Empty,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '#' => Ok(Self::Tree),
+            '.' => Ok(Self::Empty),
+            _ => Err(anyhow!("Unrecognized cell character: {}", c)),
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tree => write!(f, "#"),
+            Self::Empty => write!(f, "."),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Slope {
+    pub topography: Grid2D<Cell>,
+}
+
+impl fmt::Display for Slope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.topography)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Vector {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Slope {
+    /// The cell at `position`, with the slope repeating to the right, or `None` past the bottom.
+    pub fn get_cell(&self, position: &Vector) -> Option<Cell> {
+        if position.y < self.topography.height() {
+            Some(
+                *self
+                    .topography
+                    .get_wrapping(position.x as isize, position.y as isize),
+            )
+        } else {
+            None
+        }
+    }
+
+    /// Counts the trees hit going from the top left to the bottom of the slope, moving by `descent`
+    /// each step and wrapping around horizontally.
+    pub fn trees_in_traversal(&self, descent: &Vector) -> usize {
+        let mut position = Vector { x: 0, y: 0 };
+        let mut tree_count = 0;
+        while let Some(cell) = self.get_cell(&position) {
+            if cell == Cell::Tree {
+                tree_count += 1;
+            }
+            position.x += descent.x;
+            position.y += descent.y;
+        }
+        tree_count
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Slope;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Slope> {
+        let topography = Grid2D::parse(input, "'#' or '.'", |c| Cell::try_from(c).ok())?;
+        Ok(Slope { topography })
+    }
+
+    fn part1(slope: &Slope, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(slope)?.into())
+    }
+
+    fn part2(slope: &Slope, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(slope)?.into())
+    }
+}
+
+pub fn solve_part1(slope: &Slope) -> Result<usize> {
+    Ok(slope.trees_in_traversal(&Vector { x: 3, y: 1 }))
+}
+
+pub fn solve_part2(slope: &Slope) -> Result<usize> {
+    let descents = [
+        Vector { x: 1, y: 1 },
+        Vector { x: 3, y: 1 },
+        Vector { x: 5, y: 1 },
+        Vector { x: 7, y: 1 },
+        Vector { x: 1, y: 2 },
+    ];
+    Ok(descents
+        .iter()
+        .map(|descent| slope.trees_in_traversal(descent))
+        .product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::read_input;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let buf = read_input(TEST_INPUT).unwrap();
+        let slope = Day03::parse(&buf).unwrap();
+
+        assert_eq!(format!("{}", slope), buf);
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day03::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            7
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day03::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            336
+        );
+    }
+}
@@ -0,0 +1,326 @@
+//! Day 4: Passport Processing.
+
+use std::fmt;
+use std::io::BufRead;
+use std::str::FromStr;
+
+use anyhow::{Error, Result};
+use aoc_common::{Answer, NoParams, Records, Solution, TokenError};
+
+#[derive(Debug, PartialEq, Default)]
+pub struct Passport {
+    pub birth_year: Option<String>,
+    pub issue_year: Option<String>,
+    pub expiration_year: Option<String>,
+    pub height: Option<String>,
+    pub hair_color: Option<String>,
+    pub eye_color: Option<String>,
+    pub passport_id: Option<String>,
+    pub country_id: Option<String>,
+}
+
+impl fmt::Display for Passport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut passport_string = String::new();
+        if let Some(birth_year) = &self.birth_year {
+            passport_string.push_str(&format!("byr:{} ", birth_year));
+        }
+        if let Some(issue_year) = &self.issue_year {
+            passport_string.push_str(&format!("iyr:{} ", issue_year));
+        }
+        if let Some(expiration_year) = &self.expiration_year {
+            passport_string.push_str(&format!("eyr:{} ", expiration_year));
+        }
+        if let Some(height) = &self.height {
+            passport_string.push_str(&format!("hgt:{} ", height));
+        }
+        if let Some(hair_color) = &self.hair_color {
+            passport_string.push_str(&format!("hcl:{} ", hair_color));
+        }
+        if let Some(eye_color) = &self.eye_color {
+            passport_string.push_str(&format!("ecl:{} ", eye_color));
+        }
+        if let Some(passport_id) = &self.passport_id {
+            passport_string.push_str(&format!("pid:{} ", passport_id));
+        }
+        if let Some(country_id) = &self.country_id {
+            passport_string.push_str(&format!("cid:{} ", country_id));
+        }
+        writeln!(f, "{}", passport_string.trim_end())
+    }
+}
+
+impl FromStr for Passport {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut passport = Self::default();
+        passport.add_fields(s)?;
+        Ok(passport)
+    }
+}
+
+impl Passport {
+    /// Parses passports separated by blank lines, where each passport's fields may span lines.
+    pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Vec<Self>> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let mut passports = Vec::new();
+        for record in Records::new(&input) {
+            let mut passport = Passport::default();
+            for line in record.lines() {
+                line.parse(|text| passport.add_fields(text))?;
+            }
+            passports.push(passport);
+        }
+        Ok(passports)
+    }
+
+    fn add_fields(&mut self, s: &str) -> Result<()> {
+        for field in s.split_whitespace() {
+            let (name, value) = field
+                .split_once(':')
+                .ok_or_else(|| TokenError::new(s, field, "expected a field like name:value"))?;
+            let value = Some(value.to_owned());
+            match name {
+                "byr" => self.birth_year = value,
+                "iyr" => self.issue_year = value,
+                "eyr" => self.expiration_year = value,
+                "hgt" => self.height = value,
+                "hcl" => self.hair_color = value,
+                "ecl" => self.eye_color = value,
+                "pid" => self.passport_id = value,
+                "cid" => self.country_id = value,
+                _ => return Err(TokenError::new(s, name, "unrecognized field name").into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that every field except the optional `cid` is present.
+    pub fn validate(&self) -> bool {
+        self.birth_year.is_some()
+            && self.issue_year.is_some()
+            && self.expiration_year.is_some()
+            && self.height.is_some()
+            && self.hair_color.is_some()
+            && self.eye_color.is_some()
+            && self.passport_id.is_some()
+    }
+
+    pub fn validate_birth_year(&self) -> bool {
+        match &self.birth_year {
+            Some(birth_year) => {
+                matches!(birth_year.parse::<u32>(), Ok(year) if (1920..=2002).contains(&year))
+            }
+            None => false,
+        }
+    }
+
+    pub fn validate_issue_year(&self) -> bool {
+        match &self.issue_year {
+            Some(issue_year) => {
+                matches!(issue_year.parse::<u32>(), Ok(year) if (2010..=2020).contains(&year))
+            }
+            None => false,
+        }
+    }
+
+    pub fn validate_expiration_year(&self) -> bool {
+        match &self.expiration_year {
+            Some(expiration_year) => {
+                matches!(expiration_year.parse::<u32>(), Ok(year) if (2020..=2030).contains(&year))
+            }
+            None => false,
+        }
+    }
+
+    pub fn validate_height(&self) -> bool {
+        match &self.height {
+            Some(height) => {
+                if let Some(height) = height.strip_suffix("cm") {
+                    matches!(height.parse::<u32>(), Ok(height) if (150..=193).contains(&height))
+                } else if let Some(height) = height.strip_suffix("in") {
+                    matches!(height.parse::<u32>(), Ok(height) if (59..=76).contains(&height))
+                } else {
+                    false
+                }
+            }
+            None => false,
+        }
+    }
+
+    pub fn validate_hair_color(&self) -> bool {
+        match &self.hair_color {
+            Some(hair_color) => {
+                hair_color.starts_with("#")
+                    && hair_color.chars().skip(1).all(|c| c.is_ascii_hexdigit())
+            }
+            None => false,
+        }
+    }
+
+    pub fn validate_eye_color(&self) -> bool {
+        match &self.eye_color {
+            Some(eye_color) => {
+                matches!(
+                    eye_color.as_str(),
+                    "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth"
+                )
+            }
+            None => false,
+        }
+    }
+
+    pub fn validate_passport_id(&self) -> bool {
+        match &self.passport_id {
+            Some(passport_id) => {
+                passport_id.len() == 9 && passport_id.chars().all(|c| c.is_ascii_digit())
+            }
+            None => false,
+        }
+    }
+
+    /// Checks that every required field is present and has a valid value.
+    pub fn strict_validate(&self) -> bool {
+        self.validate_birth_year()
+            && self.validate_issue_year()
+            && self.validate_expiration_year()
+            && self.validate_height()
+            && self.validate_hair_color()
+            && self.validate_eye_color()
+            && self.validate_passport_id()
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<Passport>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<Passport>> {
+        Passport::from_reader(input.as_bytes())
+    }
+
+    fn part1(passports: &Vec<Passport>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(passports)?.into())
+    }
+
+    fn part2(passports: &Vec<Passport>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(passports)?.into())
+    }
+}
+
+pub fn solve_part1(passports: &[Passport]) -> Result<usize> {
+    Ok(passports
+        .iter()
+        .filter(|passport| passport.validate())
+        .count())
+}
+
+pub fn solve_part2(passports: &[Passport]) -> Result<usize> {
+    Ok(passports
+        .iter()
+        .filter(|passport| passport.strict_validate())
+        .count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::open_input;
+    use proptest::option;
+    use proptest::prelude::*;
+
+    fn field() -> impl Strategy<Value = Option<String>> {
+        option::of("[!-~]{0,10}")
+    }
+
+    prop_compose! {
+        fn passport()(
+            birth_year in field(),
+            issue_year in field(),
+            expiration_year in field(),
+            height in field(),
+            hair_color in field(),
+            eye_color in field(),
+            passport_id in field(),
+            country_id in field(),
+        ) -> Passport {
+            Passport {
+                birth_year,
+                issue_year,
+                expiration_year,
+                height,
+                hair_color,
+                eye_color,
+                passport_id,
+                country_id,
+            }
+        }
+    }
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+
+    #[test]
+    fn parses_input() {
+        let reader = open_input(TEST_INPUT1).unwrap();
+        let passports = Passport::from_reader(reader).unwrap();
+
+        assert_eq!(passports.len(), 4);
+        assert_eq!(
+            format!("{}", passports[0]),
+            "byr:1937 iyr:2017 eyr:2020 hgt:183cm hcl:#fffffd ecl:gry pid:860033327 cid:147\n"
+        );
+        assert_eq!(
+            format!("{}", passports[1]),
+            "byr:1929 iyr:2013 eyr:2023 hcl:#cfa07d ecl:amb pid:028048884 cid:350\n"
+        );
+        assert_eq!(
+            format!("{}", passports[2]),
+            "byr:1931 iyr:2013 eyr:2024 hgt:179cm hcl:#ae17e1 ecl:brn pid:760753108\n"
+        );
+        assert_eq!(
+            format!("{}", passports[3]),
+            "iyr:2011 eyr:2025 hgt:59in hcl:#cfa07d ecl:brn pid:166559648\n"
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_passports(passport in passport()) {
+            prop_assert_eq!(passport.to_string().parse::<Passport>().unwrap(), passport);
+        }
+
+        #[test]
+        fn parses_any_input_without_panicking(
+            input in "[^\\n]*(\n[^\\n]*){0,4}|((byr|hgt|cid|xyz)(:[#0-9a-z]*)? ?\n?){0,8}"
+        ) {
+            if let Ok(passports) = Day04::parse(&input) {
+                for passport in passports {
+                    prop_assert_eq!(passport.to_string().parse::<Passport>().unwrap(), passport);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day04::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day04::parse_file(TEST_INPUT2).unwrap()).unwrap(),
+            4
+        );
+    }
+}
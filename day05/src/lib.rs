@@ -0,0 +1,187 @@
+//! Day 5: Binary Boarding.
+
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{parse_lines, Answer, NoParams, Solution, TokenError};
+
+#[derive(Debug, PartialEq)]
+pub struct Seat {
+    pub row: u32,
+    pub col: u32,
+}
+
+impl Seat {
+    /// The seat's unique id: its row multiplied by 8, plus its column.
+    pub fn id(&self) -> u32 {
+        self.row * 8 + self.col
+    }
+}
+
+impl FromStr for Seat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut row = 0;
+        let mut col = 0;
+        let mut len = 0;
+        for (index, c) in s.char_indices() {
+            match (len, c) {
+                (0..=6, 'F') => row <<= 1,
+                (0..=6, 'B') => row = row << 1 | 1,
+                (0..=6, _) => return Err(TokenError::char_at(s, index, "expected F or B").into()),
+                (7..=9, 'L') => col <<= 1,
+                (7..=9, 'R') => col = col << 1 | 1,
+                (7..=9, _) => return Err(TokenError::char_at(s, index, "expected L or R").into()),
+                _ => {
+                    return Err(
+                        TokenError::new(s, &s[index..], "expected only 10 characters").into(),
+                    )
+                }
+            }
+            len += 1;
+        }
+        if len < 10 {
+            return Err(TokenError::missing(s, "expected 10 characters").into());
+        }
+        Ok(Self { row, col })
+    }
+}
+
+/// Prints the seat as its boarding pass, like `FBFBBFFRLR`.
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in (0..7).rev() {
+            write!(f, "{}", if self.row >> bit & 1 == 1 { 'B' } else { 'F' })?;
+        }
+        for bit in (0..3).rev() {
+            write!(f, "{}", if self.col >> bit & 1 == 1 { 'R' } else { 'L' })?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Vec<Seat>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<Seat>> {
+        Ok(parse_lines(input, Seat::from_str)?)
+    }
+
+    fn part1(seats: &Vec<Seat>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(seats)?.into())
+    }
+
+    fn part2(seats: &Vec<Seat>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(seats)?.into())
+    }
+}
+
+pub fn solve_part1(seats: &[Seat]) -> Result<u32> {
+    seats
+        .iter()
+        .map(|seat| seat.id())
+        .max()
+        .ok_or_else(|| anyhow!("No seats found in input"))
+}
+
+pub fn solve_part2(seats: &[Seat]) -> Result<u32> {
+    let mut seat_ids: Vec<u32> = seats.iter().map(|seat| seat.id()).collect();
+    seat_ids.sort_unstable();
+
+    let mut prev_seat = None;
+    for seat_id in seat_ids {
+        if let Some(prev_seat) = prev_seat {
+            if seat_id != prev_seat + 1 {
+                return Ok(prev_seat + 1);
+            }
+        }
+        prev_seat = Some(seat_id);
+    }
+
+    Err(anyhow!("No missing seat was found in input"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::ParseError;
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let seats = Day05::parse_file(TEST_INPUT).unwrap();
+        assert_eq!(seats[0], Seat { row: 70, col: 7 });
+        assert_eq!(seats[0].id(), 567);
+        assert_eq!(seats[1], Seat { row: 14, col: 7 });
+        assert_eq!(seats[1].id(), 119);
+        assert_eq!(seats[2], Seat { row: 102, col: 4 });
+        assert_eq!(seats[2].id(), 820);
+    }
+
+    #[test]
+    fn reports_malformed_seats() {
+        let error = |input: &str| {
+            Day05::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("FBFBBFFRLR\nBFFFBXFRRR"),
+            ParseError::invalid(2, 6, "X", "expected F or B")
+        );
+        assert_eq!(
+            error("FBFBBFFRL"),
+            ParseError::invalid(1, 10, "", "expected 10 characters")
+        );
+        assert_eq!(
+            error("FBFBBFFRLRR"),
+            ParseError::invalid(1, 11, "R", "expected only 10 characters")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_seats(row in 0..128u32, col in 0..8u32) {
+            let seat = Seat { row, col };
+            prop_assert_eq!(seat.to_string().parse::<Seat>().unwrap(), seat);
+        }
+
+        #[test]
+        fn parses_only_boarding_passes(line in "[^\\n]*|[FBLRX]{0,12}") {
+            let chars: Vec<char> = line.chars().collect();
+            let is_boarding_pass = chars.len() == 10
+                && chars[..7].iter().all(|&c| c == 'F' || c == 'B')
+                && chars[7..].iter().all(|&c| c == 'L' || c == 'R');
+            match line.parse::<Seat>() {
+                Ok(seat) => prop_assert_eq!(seat.to_string(), line),
+                Err(_) => prop_assert!(!is_boarding_pass),
+            }
+        }
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day05::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            820
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day05::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            120
+        );
+    }
+}
@@ -0,0 +1,113 @@
+//! Day 6: Custom Customs.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use aoc_common::{Answer, NoParams, ParseError, Records, Solution, TokenError};
+
+/// The questions each person in a group answered "yes" to, one string per person.
+pub type Group = Vec<String>;
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<Group>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<Group>> {
+        Ok(Records::new(input)
+            .map(|record| {
+                record.parse_lines(|line| match line.find(|c: char| !c.is_ascii_lowercase()) {
+                    Some(index) => {
+                        Err(
+                            TokenError::char_at(line, index, "expected a question from a to z")
+                                .into(),
+                        )
+                    }
+                    None => Ok(line.to_owned()),
+                })
+            })
+            .collect::<Result<Vec<Group>, ParseError>>()?)
+    }
+
+    fn part1(groups: &Vec<Group>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(groups)?.into())
+    }
+
+    fn part2(groups: &Vec<Group>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(groups)?.into())
+    }
+}
+
+pub fn solve_part1(groups: &[Group]) -> Result<usize> {
+    Ok(groups
+        .iter()
+        .map(|group| {
+            let mut answered = HashSet::new();
+            for person_answers in group {
+                for c in person_answers.chars() {
+                    answered.insert(c);
+                }
+            }
+            answered.len()
+        })
+        .sum())
+}
+
+pub fn solve_part2(groups: &[Group]) -> Result<usize> {
+    Ok(groups
+        .iter()
+        .map(|group| {
+            let mut answer_counts = HashMap::new();
+            for person_answers in group {
+                for c in person_answers.chars() {
+                    let counter = answer_counts.entry(c).or_insert(0);
+                    *counter += 1;
+                }
+            }
+            answer_counts
+                .values()
+                .filter(|&&count| count == group.len())
+                .count()
+        })
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        assert_eq!(
+            Day06::parse("ab\r\nac\r\n\r\n\r\nb\r\n").unwrap(),
+            vec![vec!["ab", "ac"], vec!["b"]]
+        );
+        assert_eq!(
+            Day06::parse("ab\n\naC\n")
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap(),
+            ParseError::invalid(3, 2, "C", "expected a question from a to z")
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day06::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            11
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day06::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            6
+        );
+    }
+}
@@ -0,0 +1,310 @@
+//! Day 7: Handy Haversacks.
+
+#[macro_use]
+extern crate lazy_static;
+extern crate maplit;
+
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{Answer, NoParams, ParseError, Solution, TokenError};
+use regex::Regex;
+
+lazy_static! {
+    static ref CONTAINER_BAG: Regex =
+        Regex::new(r"(?P<container_color>[\w\s]+) bags contain").unwrap();
+    static ref INNER_BAGS: Regex =
+        Regex::new(r"(?P<bag_quantity>\d+) (?P<bag_color>[\w\s]+) bags?(, )?").unwrap();
+}
+
+pub type InnerToContainerBags = HashMap<String, HashSet<String>>;
+pub type ContainerToInnerBags = HashMap<String, Vec<(u32, String)>>;
+
+/// The bag rules, mapped both from each bag to the bags that can contain it and from each bag to
+/// the bags it must contain.
+#[derive(Debug, PartialEq)]
+pub struct Rules {
+    pub containers: InnerToContainerBags,
+    pub contents: ContainerToInnerBags,
+}
+
+/// A container bag's color and the quantities and colors of the bags it directly contains.
+type BagRule = (String, Vec<(u32, String)>);
+
+fn parse_rule(rule: &str) -> Result<BagRule> {
+    let container_color = CONTAINER_BAG
+        .captures(rule)
+        .ok_or_else(|| TokenError::new(rule, rule, "expected \"<color> bags contain\""))?
+        ["container_color"]
+        .to_string();
+    let inner_bags = INNER_BAGS
+        .captures_iter(rule)
+        .map(|captures| {
+            let quantity = &captures["bag_quantity"];
+            let quantity = quantity
+                .parse()
+                .map_err(|_| TokenError::new(rule, quantity, "expected a bag quantity"))?;
+            Ok((quantity, captures["bag_color"].to_string()))
+        })
+        .collect::<Result<Vec<(u32, String)>>>()?;
+    Ok((container_color, inner_bags))
+}
+
+fn parse_rules<R: BufRead>(reader: R) -> Result<Vec<BagRule>> {
+    let mut rules = Vec::new();
+    for (index, rule) in reader.lines().enumerate() {
+        let rule = rule?;
+        rules.push(
+            parse_rule(&rule).map_err(|error| ParseError::from_line(index + 1, &rule, error))?,
+        );
+    }
+    Ok(rules)
+}
+
+/// Maps each bag color to the quantities and colors of the bags it directly contains.
+pub fn map_from_reader<R: BufRead>(reader: R) -> Result<ContainerToInnerBags> {
+    Ok(parse_rules(reader)?.into_iter().collect())
+}
+
+/// Inverts the contents map, mapping each bag color to the colors of the bags that directly
+/// contain it.
+pub fn reverse_map(contents: &ContainerToInnerBags) -> InnerToContainerBags {
+    let mut bags = HashMap::new();
+    for (container_color, inner_bags) in contents {
+        for (_, color) in inner_bags {
+            let container_bags = bags.entry(color.clone()).or_insert_with(HashSet::new);
+            container_bags.insert(container_color.clone());
+        }
+    }
+    bags
+}
+
+/// Describes a cycle of bags, each listed bag directly containing the next.
+fn cycle_error(cycle: &[&str]) -> Error {
+    anyhow!("The bags contain each other: {}", cycle.join(" -> "))
+}
+
+/// Visits every bag that can eventually contain `color`, tracking the bags on the current `path`
+/// so that a cycle fails instead of going unnoticed.
+fn visit_container_bags<'a>(
+    bags: &'a InnerToContainerBags,
+    visited: &mut HashSet<&'a str>,
+    path: &mut Vec<&'a str>,
+    color: &'a str,
+) -> Result<()> {
+    if let Some(start) = path.iter().position(|&bag| bag == color) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(color);
+        cycle.reverse();
+        return Err(cycle_error(&cycle));
+    }
+    if visited.contains(color) {
+        return Ok(());
+    }
+    visited.insert(color);
+
+    path.push(color);
+    for container_bag in bags.get(color).into_iter().flatten() {
+        visit_container_bags(bags, visited, path, container_bag)?;
+    }
+    path.pop();
+    Ok(())
+}
+
+/// Counts `color` along with every bag inside it, tracking the bags on the current `path` so that
+/// a cycle fails instead of recursing forever.
+fn count_inner_bags<'a>(
+    bags: &'a ContainerToInnerBags,
+    cached: &mut HashMap<&'a str, u32>,
+    path: &mut Vec<&'a str>,
+    color: &'a str,
+) -> Result<u32> {
+    if let Some(&val) = cached.get(color) {
+        return Ok(val);
+    }
+    if let Some(start) = path.iter().position(|&bag| bag == color) {
+        let mut cycle = path[start..].to_vec();
+        cycle.push(color);
+        return Err(cycle_error(&cycle));
+    }
+
+    path.push(color);
+    let mut val = 1;
+    for (quantity, inner_bag) in bags.get(color).into_iter().flatten() {
+        val += quantity * count_inner_bags(bags, cached, path, inner_bag)?;
+    }
+    path.pop();
+    cached.insert(color, val);
+    Ok(val)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Rules;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Rules> {
+        let contents = map_from_reader(input.as_bytes())?;
+        Ok(Rules {
+            containers: reverse_map(&contents),
+            contents,
+        })
+    }
+
+    fn part1(rules: &Rules, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(rules)?.into())
+    }
+
+    fn part2(rules: &Rules, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(rules)?.into())
+    }
+}
+
+pub fn solve_part1(rules: &Rules) -> Result<usize> {
+    let mut visited = HashSet::new();
+    visit_container_bags(
+        &rules.containers,
+        &mut visited,
+        &mut Vec::new(),
+        "shiny gold",
+    )?;
+    Ok(visited.len() - 1)
+}
+
+pub fn solve_part2(rules: &Rules) -> Result<u32> {
+    let mut cached = HashMap::new();
+    Ok(count_inner_bags(&rules.contents, &mut cached, &mut Vec::new(), "shiny gold")? - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::{hashmap, hashset};
+
+    use super::*;
+
+    use aoc_common::open_input;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+
+    #[test]
+    fn parses_input_to_reverse_map() {
+        let reader = open_input(TEST_INPUT1).unwrap();
+        let bags = reverse_map(&map_from_reader(reader).unwrap());
+        let expected = hashmap! {
+            "vibrant plum".into() => hashset! {
+                "shiny gold".into(),
+            },
+            "shiny gold".into() => hashset! {
+                "bright white".into(),
+                "muted yellow".into(),
+            },
+            "bright white".into() => hashset! {
+                "dark orange".into(),
+                "light red".into(),
+            },
+            "dark olive".into() => hashset! {
+                "shiny gold".into(),
+            },
+            "dotted black".into() => hashset! {
+                "vibrant plum".into(),
+                "dark olive".into(),
+            },
+            "muted yellow".into() => hashset! {
+                "light red".into(),
+                "dark orange".into(),
+            },
+            "faded blue".into() => hashset! {
+                "dark olive".into(),
+                "vibrant plum".into(),
+                "muted yellow".into(),
+            },
+        };
+        assert_eq!(bags, expected);
+    }
+
+    #[test]
+    fn parses_input_to_map() {
+        let reader = open_input(TEST_INPUT1).unwrap();
+        let bags = map_from_reader(reader).unwrap();
+        let expected = hashmap! {
+            "light red".into() => std::vec![
+                (1, "bright white".into()),
+                (2, "muted yellow".into()),
+            ],
+            "dark orange".into() => std::vec![
+                (3, "bright white".into()),
+                (4, "muted yellow".into()),
+            ],
+            "bright white".into() => std::vec![
+                (1, "shiny gold".into()),
+            ],
+            "muted yellow".into() => std::vec![
+                (2, "shiny gold".into()),
+                (9, "faded blue".into()),
+            ],
+            "shiny gold".into() => std::vec![
+                (1, "dark olive".into()),
+                (2, "vibrant plum".into()),
+            ],
+            "dark olive".into() => std::vec![
+                (3, "faded blue".into()),
+                (4, "dotted black".into()),
+            ],
+            "vibrant plum".into() => std::vec![
+                (5, "faded blue".into()),
+                (6, "dotted black".into()),
+            ],
+            "faded blue".into() => std::vec![],
+            "dotted black".into() => std::vec![],
+        };
+        assert_eq!(bags, expected);
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day07::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day07::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            32
+        );
+        assert_eq!(
+            solve_part2(&Day07::parse_file(TEST_INPUT2).unwrap()).unwrap(),
+            126
+        );
+    }
+
+    #[test]
+    fn fails_on_cycles() {
+        let rules = Day07::parse(
+            "light red bags contain 1 dark blue bag.\n\
+             dark blue bags contain 1 light red bag, 2 shiny gold bags.\n\
+             shiny gold bags contain no other bags.\n",
+        )
+        .unwrap();
+        assert_eq!(
+            solve_part1(&rules).unwrap_err().to_string(),
+            "The bags contain each other: dark blue -> light red -> dark blue"
+        );
+        let rules = Day07::parse(
+            "shiny gold bags contain 1 light red bag.\n\
+             light red bags contain 1 dark blue bag.\n\
+             dark blue bags contain 1 light red bag.\n",
+        )
+        .unwrap();
+        assert_eq!(
+            solve_part2(&rules).unwrap_err().to_string(),
+            "The bags contain each other: light red -> dark blue -> light red"
+        );
+    }
+}
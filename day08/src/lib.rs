@@ -0,0 +1,200 @@
+//! Day 8: Handheld Halting.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{parse_lines, Answer, NoParams, Solution, TokenError};
+
+#[derive(Debug, PartialEq)]
+pub enum Operation {
+    Nop(i32),
+    Acc(i32),
+    Jmp(i32),
+}
+
+impl FromStr for Operation {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (op, offset) = s
+            .split_once(' ')
+            .ok_or_else(|| TokenError::missing(s, "expected an offset after the operation"))?;
+        let offset: i32 = offset
+            .parse()
+            .map_err(|_| TokenError::new(s, offset, "expected a signed offset"))?;
+        match op {
+            "nop" => Ok(Operation::Nop(offset)),
+            "acc" => Ok(Operation::Acc(offset)),
+            "jmp" => Ok(Operation::Jmp(offset)),
+            _ => Err(TokenError::new(s, op, "expected nop, acc or jmp").into()),
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Nop(offset) => write!(f, "nop {:+}", offset),
+            Operation::Acc(offset) => write!(f, "acc {:+}", offset),
+            Operation::Jmp(offset) => write!(f, "jmp {:+}", offset),
+        }
+    }
+}
+
+/// Runs the program until an instruction is about to run a second time and returns the
+/// accumulator at that point, or fails if the program leaves its instructions before looping.
+pub fn find_infinite_loop(program: &[Operation]) -> Result<i32> {
+    let mut accumulator: i32 = 0;
+    let mut pointer: i32 = 0;
+    let mut visited = HashSet::new();
+    while !visited.contains(&pointer) {
+        visited.insert(pointer);
+        let operation = usize::try_from(pointer)
+            .ok()
+            .and_then(|pointer| program.get(pointer))
+            .ok_or_else(|| anyhow!("Program jumped to instruction {} without looping", pointer))?;
+        match operation {
+            Operation::Nop(_) => pointer += 1,
+            Operation::Acc(offset) => {
+                accumulator += offset;
+                pointer += 1;
+            }
+            Operation::Jmp(offset) => pointer += offset,
+        }
+    }
+    Ok(accumulator)
+}
+
+/// Runs the program with the `nop` or `jmp` at `flip_pointer` swapped for the other, returning the
+/// accumulator if the program terminates or `None` if it loops or jumps out of bounds.
+pub fn find_terminated_value(program: &[Operation], flip_pointer: i32) -> Option<i32> {
+    let mut accumulator: i32 = 0;
+    let mut pointer: i32 = 0;
+    let mut visited = HashSet::new();
+    while !visited.contains(&pointer) {
+        if pointer == program.len() as i32 {
+            return Some(accumulator);
+        } else if pointer >= program.len() as i32 || pointer < 0 {
+            return None;
+        }
+        visited.insert(pointer);
+        match program[pointer as usize] {
+            Operation::Nop(offset) if flip_pointer == pointer => pointer += offset,
+            Operation::Nop(_) => pointer += 1,
+            Operation::Acc(offset) => {
+                accumulator += offset;
+                pointer += 1;
+            }
+            Operation::Jmp(_) if flip_pointer == pointer => pointer += 1,
+            Operation::Jmp(offset) => pointer += offset,
+        }
+    }
+    None
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Vec<Operation>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<Operation>> {
+        Ok(parse_lines(input, Operation::from_str)?)
+    }
+
+    fn part1(program: &Vec<Operation>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(program)?.into())
+    }
+
+    fn part2(program: &Vec<Operation>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(program)?.into())
+    }
+}
+
+pub fn solve_part1(program: &[Operation]) -> Result<i32> {
+    find_infinite_loop(program)
+}
+
+pub fn solve_part2(program: &[Operation]) -> Result<i32> {
+    let mut flip_pointer: i32 = 0;
+    while flip_pointer < program.len() as i32 {
+        if matches!(
+            program[flip_pointer as usize],
+            Operation::Nop(_) | Operation::Jmp(_)
+        ) {
+            if let Some(result) = find_terminated_value(program, flip_pointer) {
+                return Ok(result);
+            }
+        }
+        flip_pointer += 1;
+    }
+    Err(anyhow!("No fix found"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let instructions = Day08::parse_file(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            instructions,
+            vec![
+                Operation::Nop(0),
+                Operation::Acc(1),
+                Operation::Jmp(4),
+                Operation::Acc(3),
+                Operation::Jmp(-3),
+                Operation::Acc(-99),
+                Operation::Acc(1),
+                Operation::Jmp(-4),
+                Operation::Acc(6),
+            ]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_operations(op in 0..3, offset: i32) {
+            let operation = match op {
+                0 => Operation::Nop(offset),
+                1 => Operation::Acc(offset),
+                _ => Operation::Jmp(offset),
+            };
+            prop_assert_eq!(operation.to_string().parse::<Operation>().unwrap(), operation);
+        }
+
+        #[test]
+        fn parses_any_line_without_panicking(line in "[^\\n]*|(nop|acc|jmp|xyz) ?[+-]?[0-9]{0,12}") {
+            if let Ok(operation) = line.parse::<Operation>() {
+                prop_assert_eq!(operation.to_string().parse::<Operation>().unwrap(), operation);
+            }
+        }
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day08::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day08::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            8
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Day 9: Encoding Error.
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse_lines, Answer, Solution};
+use serde::Deserialize;
+use tracing::debug;
+
+/// How many numbers precede each number that must sum to it.
+pub const PREAMBLE: usize = 25;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub preamble: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { preamble: PREAMBLE }
+    }
+}
+
+/// Finds the first number that isn't the sum of two different numbers among the `preamble` numbers
+/// before it.
+pub fn find_invalid_num(nums: &[usize], preamble: usize) -> Option<usize> {
+    if let Some(num) = nums.windows(preamble + 1).find(|chunk| {
+        for num in &chunk[0..preamble] {
+            for other_num in &chunk[0..preamble] {
+                if num != other_num && num + other_num == chunk[preamble] {
+                    return false;
+                }
+            }
+        }
+        true
+    }) {
+        return Some(num[preamble]);
+    }
+    None
+}
+
+/// Finds a contiguous run of at least two numbers summing to `invalid_num` and returns the sum of
+/// its smallest and largest numbers.
+pub fn find_encryption_weakness(nums: &[usize], invalid_num: usize) -> Option<usize> {
+    let mut window_size = 2;
+    while window_size < 1000 {
+        if let Some(weakness) = nums
+            .windows(window_size)
+            .find(|chunk| chunk.iter().sum::<usize>() == invalid_num)
+        {
+            return Some(
+                weakness.iter().min().expect("non-empty slice")
+                    + weakness.iter().max().expect("non-empty slice"),
+            );
+        } else {
+            window_size += 1;
+        }
+    }
+    None
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<usize>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        Ok(parse_lines(input, |line| Ok(line.parse()?))?)
+    }
+
+    fn part1(nums: &Vec<usize>, params: &Params) -> Result<Answer> {
+        Ok(solve_part1(nums, params.preamble)?.into())
+    }
+
+    fn part2(nums: &Vec<usize>, params: &Params) -> Result<Answer> {
+        let invalid_num = solve_part1(nums, params.preamble)?;
+        debug!(
+            invalid_num,
+            "looking for a run that sums to the invalid number"
+        );
+        Ok(solve_part2(nums, invalid_num)?.into())
+    }
+}
+
+pub fn solve_part1(nums: &[usize], preamble: usize) -> Result<usize> {
+    find_invalid_num(nums, preamble).ok_or_else(|| {
+        anyhow!(
+            "Every number is the sum of two of the {} numbers before it",
+            preamble
+        )
+    })
+}
+
+pub fn solve_part2(nums: &[usize], invalid_num: usize) -> Result<usize> {
+    find_encryption_weakness(nums, invalid_num)
+        .ok_or_else(|| anyhow!("No contiguous numbers sum to {}", invalid_num))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day09::parse_file(TEST_INPUT).unwrap(), 5).unwrap(),
+            127
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day09::parse_file(TEST_INPUT).unwrap(), 127).unwrap(),
+            62
+        );
+    }
+
+    #[test]
+    fn fails_without_an_answer() {
+        let nums = Day09::parse_file(TEST_INPUT).unwrap();
+        assert!(solve_part1(&nums[..5], 5).is_err());
+        assert!(solve_part2(&nums, 1).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+//! Day 10: Adapter Array.
+
+use std::collections::{HashMap, HashSet};
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse_lines, Answer, NoParams, Solution};
+
+fn find_jolt_differences(
+    adapters: &mut HashSet<usize>,
+    input_jolt: usize,
+    jolt_differences: &mut HashMap<usize, usize>,
+) {
+    for i in 1..=3 {
+        if adapters.remove(&(input_jolt + i)) {
+            let entry = jolt_differences.entry(i).or_insert(0);
+            *entry += 1;
+            find_jolt_differences(adapters, input_jolt + i, jolt_differences)
+        }
+    }
+}
+
+fn count_adapter_combinations(
+    adapters: &HashSet<usize>,
+    input_jolt: usize,
+    target_jolt: usize,
+    cache: &mut HashMap<usize, usize>,
+) -> usize {
+    if let Some(count) = cache.get(&input_jolt) {
+        *count
+    } else {
+        let count = if input_jolt == target_jolt {
+            1
+        } else {
+            (1..=3)
+                .map(|i| {
+                    if adapters.contains(&(input_jolt + i)) {
+                        count_adapter_combinations(adapters, input_jolt + i, target_jolt, cache)
+                    } else {
+                        0
+                    }
+                })
+                .sum()
+        };
+        cache.insert(input_jolt, count);
+        count
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = HashSet<usize>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<HashSet<usize>> {
+        Ok(parse_lines(input, |line| Ok(line.parse()?))?
+            .into_iter()
+            .collect())
+    }
+
+    fn part1(adapters: &HashSet<usize>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(adapters)?.into())
+    }
+
+    fn part2(adapters: &HashSet<usize>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(adapters)?.into())
+    }
+}
+
+pub fn solve_part1(adapters: &HashSet<usize>) -> Result<usize> {
+    let mut adapters = adapters.clone();
+    let mut differences = HashMap::new();
+    differences.insert(1, 0);
+    differences.insert(2, 0);
+    differences.insert(3, 0);
+    find_jolt_differences(&mut adapters, 0, &mut differences);
+    Ok(differences[&1] * (differences[&3] + 1))
+}
+
+pub fn solve_part2(adapters: &HashSet<usize>) -> Result<usize> {
+    let target_jolt = *adapters
+        .iter()
+        .max()
+        .ok_or_else(|| anyhow!("No adapters found in input"))?;
+    let mut cache = HashMap::new();
+    Ok(count_adapter_combinations(
+        adapters,
+        0,
+        target_jolt,
+        &mut cache,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day10::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            35
+        );
+        assert_eq!(
+            solve_part1(&Day10::parse_file(TEST_INPUT2).unwrap()).unwrap(),
+            220
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day10::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            8
+        );
+        assert_eq!(
+            solve_part2(&Day10::parse_file(TEST_INPUT2).unwrap()).unwrap(),
+            19208
+        );
+    }
+}
@@ -0,0 +1,455 @@
+//! Day 11: Seating System.
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::BufRead;
+
+use anyhow::{anyhow, bail, Error, Result};
+use aoc_common::grid::ADJACENT_VECTORS;
+use aoc_common::{Answer, Grid2D, Solution};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl TryFrom<char> for Seat {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Seat::Floor),
+            'L' => Ok(Seat::Empty),
+            '#' => Ok(Seat::Occupied),
+            _ => Err(anyhow!("Unrecognized seat character: {}", c)),
+        }
+    }
+}
+
+impl fmt::Display for Seat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Floor => write!(f, "."),
+            Self::Empty => write!(f, "L"),
+            Self::Occupied => write!(f, "#"),
+        }
+    }
+}
+
+impl Seat {
+    pub fn is_occupied(&self) -> bool {
+        *self == Seat::Occupied
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Grid {
+    seats: Grid2D<Seat>,
+}
+
+impl Grid {
+    pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let seats = Grid2D::parse(&input, "'.', 'L' or '#'", |c| Seat::try_from(c).ok())?;
+        Ok(Self { seats })
+    }
+
+    /// The first seat from `position` in the direction of `vector`, looking past floor.
+    fn seat_in_sight(&self, position: (usize, usize), vector: (isize, isize)) -> Seat {
+        let mut position = position;
+        while let Some(next_position) = self.seats.offset(position, vector) {
+            let seat = self.seats[next_position];
+            if seat != Seat::Floor {
+                return seat;
+            }
+            position = next_position;
+        }
+        Seat::Floor
+    }
+
+    /// With `recurse`, counts the first seat seen in each direction instead of just the adjacent
+    /// seats.
+    fn occupied_in_sight(&self, position: (usize, usize), recurse: bool) -> usize {
+        if recurse {
+            ADJACENT_VECTORS
+                .iter()
+                .filter(|&&vector| self.seat_in_sight(position, vector).is_occupied())
+                .count()
+        } else {
+            self.seats
+                .neighbors(position)
+                .filter(|(_, seat)| seat.is_occupied())
+                .count()
+        }
+    }
+
+    fn new_seat_state(
+        &self,
+        position: (usize, usize),
+        seat: &Seat,
+        recurse: bool,
+        empty_threshold: usize,
+    ) -> Seat {
+        match seat {
+            Seat::Floor => Seat::Floor,
+            Seat::Empty => {
+                if self.occupied_in_sight(position, recurse) == 0 {
+                    Seat::Occupied
+                } else {
+                    Seat::Empty
+                }
+            }
+            Seat::Occupied => {
+                if self.occupied_in_sight(position, recurse) >= empty_threshold {
+                    Seat::Empty
+                } else {
+                    Seat::Occupied
+                }
+            }
+        }
+    }
+
+    /// Applies the seating rules to every seat at once and returns whether any seat changed. With
+    /// `recurse`, seats look past floor to the first seat in each direction.
+    pub fn run_round(&mut self, recurse: bool, empty_threshold: usize) -> bool {
+        let new_seats = self
+            .seats
+            .map(|position, seat| self.new_seat_state(position, seat, recurse, empty_threshold));
+        let changed = new_seats != self.seats;
+        self.seats = new_seats;
+        changed
+    }
+
+    pub fn occupied_count(&self) -> usize {
+        self.seats
+            .iter()
+            .filter(|(_, seat)| seat.is_occupied())
+            .count()
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.seats)
+    }
+}
+
+/// How many occupied seats it takes for someone to leave their seat, counting the adjacent seats in
+/// part 1 and the visible ones in part 2.
+pub const ADJACENT_THRESHOLD: usize = 4;
+pub const VISIBLE_THRESHOLD: usize = 5;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub adjacent_threshold: usize,
+    pub visible_threshold: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            adjacent_threshold: ADJACENT_THRESHOLD,
+            visible_threshold: VISIBLE_THRESHOLD,
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Grid;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Grid> {
+        Grid::from_reader(input.as_bytes())
+    }
+
+    fn part1(grid: &Grid, params: &Params) -> Result<Answer> {
+        Ok(solve_part1(grid, params.adjacent_threshold)?.into())
+    }
+
+    fn part2(grid: &Grid, params: &Params) -> Result<Answer> {
+        Ok(solve_part2(grid, params.visible_threshold)?.into())
+    }
+}
+
+/// Runs rounds until no seat changes and counts the occupied seats. Fails if the seating comes
+/// back around to one it has already been in, since then it never settles.
+fn settle(grid: &Grid, recurse: bool, threshold: usize) -> Result<usize> {
+    let mut grid = grid.clone();
+    let mut seen = HashSet::new();
+    while grid.run_round(recurse, threshold) {
+        if !seen.insert(grid.seats.clone()) {
+            bail!(
+                "The seats never settle with a threshold of {}, they keep repeating",
+                threshold
+            );
+        }
+    }
+    Ok(grid.occupied_count())
+}
+
+pub fn solve_part1(grid: &Grid, threshold: usize) -> Result<usize> {
+    settle(grid, false, threshold)
+}
+
+pub fn solve_part2(grid: &Grid, threshold: usize) -> Result<usize> {
+    settle(grid, true, threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::{open_input, read_input};
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let reader = open_input(TEST_INPUT).unwrap();
+        let grid = Grid::from_reader(reader).unwrap();
+
+        let buf = read_input(TEST_INPUT).unwrap();
+
+        assert_eq!(format!("{}", grid), buf);
+    }
+
+    #[test]
+    fn runs_rounds_part1() {
+        let reader = open_input(TEST_INPUT).unwrap();
+        let mut grid = Grid::from_reader(reader).unwrap();
+
+        grid.run_round(false, 4);
+        let expected = r#"
+            #.##.##.##
+            #######.##
+            #.#.#..#..
+            ####.##.##
+            #.##.##.##
+            #.#####.##
+            ..#.#.....
+            ##########
+            #.######.#
+            #.#####.##
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(false, 4);
+        let expected = r#"
+            #.LL.L#.##
+            #LLLLLL.L#
+            L.L.L..L..
+            #LLL.LL.L#
+            #.LL.LL.LL
+            #.LLLL#.##
+            ..L.L.....
+            #LLLLLLLL#
+            #.LLLLLL.L
+            #.#LLLL.##
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(false, 4);
+        let expected = r#"
+            #.##.L#.##
+            #L###LL.L#
+            L.#.#..#..
+            #L##.##.L#
+            #.##.LL.LL
+            #.###L#.##
+            ..#.#.....
+            #L######L#
+            #.LL###L.L
+            #.#L###.##
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(false, 4);
+        let expected = r#"
+            #.#L.L#.##
+            #LLL#LL.L#
+            L.L.L..#..
+            #LLL.##.L#
+            #.LL.LL.LL
+            #.LL#L#.##
+            ..L.L.....
+            #L#LLLL#L#
+            #.LLLLLL.L
+            #.#L#L#.##
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(false, 4);
+        let expected = r#"
+            #.#L.L#.##
+            #LLL#LL.L#
+            L.#.L..#..
+            #L##.##.L#
+            #.#L.LL.LL
+            #.#L#L#.##
+            ..L.L.....
+            #L#L##L#L#
+            #.LLLLLL.L
+            #.#L#L#.##
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+    }
+
+    #[test]
+    fn runs_rounds_part2() {
+        let reader = open_input(TEST_INPUT).unwrap();
+        let mut grid = Grid::from_reader(reader).unwrap();
+
+        grid.run_round(true, 5);
+        let expected = r#"
+            #.##.##.##
+            #######.##
+            #.#.#..#..
+            ####.##.##
+            #.##.##.##
+            #.#####.##
+            ..#.#.....
+            ##########
+            #.######.#
+            #.#####.##
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(true, 5);
+        let expected = r#"
+            #.LL.LL.L#
+            #LLLLLL.LL
+            L.L.L..L..
+            LLLL.LL.LL
+            L.LL.LL.LL
+            L.LLLLL.LL
+            ..L.L.....
+            LLLLLLLLL#
+            #.LLLLLL.L
+            #.LLLLL.L#
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(true, 5);
+        let expected = r#"
+            #.L#.##.L#
+            #L#####.LL
+            L.#.#..#..
+            ##L#.##.##
+            #.##.#L.##
+            #.#####.#L
+            ..#.#.....
+            LLL####LL#
+            #.L#####.L
+            #.L####.L#
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(true, 5);
+        let expected = r#"
+            #.L#.L#.L#
+            #LLLLLL.LL
+            L.L.L..#..
+            ##LL.LL.L#
+            L.LL.LL.L#
+            #.LLLLL.LL
+            ..L.L.....
+            LLLLLLLLL#
+            #.LLLLL#.L
+            #.L#LL#.L#
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(true, 5);
+        let expected = r#"
+            #.L#.L#.L#
+            #LLLLLL.LL
+            L.L.L..#..
+            ##L#.#L.L#
+            L.L#.#L.L#
+            #.L####.LL
+            ..#.#.....
+            LLL###LLL#
+            #.LLLLL#.L
+            #.L#LL#.L#
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+
+        grid.run_round(true, 5);
+        let expected = r#"
+            #.L#.L#.L#
+            #LLLLLL.LL
+            L.L.L..#..
+            ##L#.#L.L#
+            L.L#.LL.L#
+            #.LLLL#.LL
+            ..#.L.....
+            LLL###LLL#
+            #.LLLLL#.L
+            #.L#LL#.L#
+        "#
+        .trim_start()
+        .replace(" ", "");
+        assert_eq!(format!("{}", grid), expected);
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day11::parse_file(TEST_INPUT).unwrap(), ADJACENT_THRESHOLD).unwrap(),
+            37
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day11::parse_file(TEST_INPUT).unwrap(), VISIBLE_THRESHOLD).unwrap(),
+            26
+        );
+    }
+
+    #[test]
+    fn fails_to_settle() {
+        let grid = Day11::parse("LL\nLL\n").unwrap();
+        for solve in [solve_part1, solve_part2] {
+            assert_eq!(
+                solve(&grid, 1).unwrap_err().to_string(),
+                "The seats never settle with a threshold of 1, they keep repeating"
+            );
+            assert_eq!(
+                solve(&grid, 0).unwrap_err().to_string(),
+                "The seats never settle with a threshold of 0, they keep repeating"
+            );
+            assert_eq!(solve(&grid, 4).unwrap(), 4);
+        }
+    }
+}
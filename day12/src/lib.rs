@@ -0,0 +1,357 @@
+//! Day 12: Rain Risk.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::AddAssign;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{parse_lines, Answer, NoParams, Solution, TokenError};
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Coordinate {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl AddAssign for Coordinate {
+    fn add_assign(&mut self, other: Self) {
+        *self = Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        };
+    }
+}
+
+impl Coordinate {
+    pub fn rotate(&self, instruction: &NavInstruction) -> Self {
+        let mut degrees = instruction.value;
+        let mut coordinate = *self;
+        while degrees > 0 {
+            coordinate = match instruction.action {
+                Action::Left => Self {
+                    x: coordinate.y,
+                    y: -coordinate.x,
+                },
+                Action::Right => Self {
+                    x: -coordinate.y,
+                    y: coordinate.x,
+                },
+                _ => return coordinate,
+            };
+            degrees -= 90;
+        }
+        coordinate
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Action {
+    North,
+    South,
+    East,
+    West,
+    Left,
+    Right,
+    Forward,
+}
+
+impl TryFrom<char> for Action {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            'N' => Ok(Action::North),
+            'S' => Ok(Action::South),
+            'E' => Ok(Action::East),
+            'W' => Ok(Action::West),
+            'L' => Ok(Action::Left),
+            'R' => Ok(Action::Right),
+            'F' => Ok(Action::Forward),
+            _ => Err(anyhow!("Unrecognized action character: {}", c)),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::North => write!(f, "N"),
+            Action::South => write!(f, "S"),
+            Action::East => write!(f, "E"),
+            Action::West => write!(f, "W"),
+            Action::Left => write!(f, "L"),
+            Action::Right => write!(f, "R"),
+            Action::Forward => write!(f, "F"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct NavInstruction {
+    pub action: Action,
+    pub value: i32,
+}
+
+impl FromStr for NavInstruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        let action = chars
+            .next()
+            .ok_or_else(|| TokenError::missing(s, "expected an action"))?;
+        let action = Action::try_from(action)
+            .map_err(|_| TokenError::char_at(s, 0, "expected N, S, E, W, L, R or F"))?;
+        let value = chars.as_str();
+        let value = match (&action, value.parse()) {
+            (Action::Left | Action::Right, Ok(degrees)) if degrees >= 0 && degrees % 90 == 0 => {
+                degrees
+            }
+            (Action::Left | Action::Right, _) => {
+                return Err(TokenError::new(s, value, "expected a multiple of 90 degrees").into())
+            }
+            (_, Ok(value)) if value >= 0 => value,
+            _ => return Err(TokenError::new(s, value, "expected a non-negative number").into()),
+        };
+        Ok(Self { action, value })
+    }
+}
+
+impl fmt::Display for NavInstruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.action, self.value)
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Bearing {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Bearing {
+    pub fn rotate(&self, instruction: &NavInstruction) -> Self {
+        let mut degrees = instruction.value;
+        let mut bearing = *self;
+        while degrees > 0 {
+            bearing = match instruction.action {
+                Action::Left => match bearing {
+                    Bearing::North => Bearing::West,
+                    Bearing::South => Bearing::East,
+                    Bearing::East => Bearing::North,
+                    Bearing::West => Bearing::South,
+                },
+                Action::Right => match bearing {
+                    Bearing::North => Bearing::East,
+                    Bearing::South => Bearing::West,
+                    Bearing::East => Bearing::South,
+                    Bearing::West => Bearing::North,
+                },
+                _ => return bearing,
+            };
+            degrees -= 90;
+        }
+        bearing
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Ship {
+    pub bearing: Bearing,
+    pub position: Coordinate,
+    pub waypoint: Coordinate,
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ship {
+    pub fn new() -> Self {
+        Self {
+            bearing: Bearing::East,
+            position: Coordinate { x: 0, y: 0 },
+            waypoint: Coordinate { x: 10, y: -1 },
+        }
+    }
+
+    /// Moves the ship itself, turning it with `L` and `R`.
+    pub fn apply_instruction(&mut self, instruction: &NavInstruction) {
+        match instruction.action {
+            Action::Left | Action::Right => self.bearing = self.bearing.rotate(instruction),
+            Action::North => self.position.y -= instruction.value,
+            Action::South => self.position.y += instruction.value,
+            Action::East => self.position.x += instruction.value,
+            Action::West => self.position.x -= instruction.value,
+            Action::Forward => {
+                match self.bearing {
+                    Bearing::North => self.position.y -= instruction.value,
+                    Bearing::South => self.position.y += instruction.value,
+                    Bearing::East => self.position.x += instruction.value,
+                    Bearing::West => self.position.x -= instruction.value,
+                };
+            }
+        }
+    }
+
+    /// Moves the waypoint (relative to the ship) and only moves the ship towards it with `F`.
+    pub fn apply_waypoint_instruction(&mut self, instruction: &NavInstruction) {
+        match instruction.action {
+            Action::Left | Action::Right => self.waypoint = self.waypoint.rotate(instruction),
+            Action::North => self.waypoint.y -= instruction.value,
+            Action::South => self.waypoint.y += instruction.value,
+            Action::East => self.waypoint.x += instruction.value,
+            Action::West => self.waypoint.x -= instruction.value,
+            Action::Forward => {
+                let mut value = instruction.value;
+                while value > 0 {
+                    self.position += self.waypoint;
+                    value -= 1;
+                }
+            }
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Vec<NavInstruction>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<NavInstruction>> {
+        Ok(parse_lines(input, NavInstruction::from_str)?)
+    }
+
+    fn part1(instructions: &Vec<NavInstruction>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(instructions)?.into())
+    }
+
+    fn part2(instructions: &Vec<NavInstruction>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(instructions)?.into())
+    }
+}
+
+pub fn solve_part1(instructions: &[NavInstruction]) -> Result<i32> {
+    let mut ship = Ship::new();
+    for instruction in instructions {
+        ship.apply_instruction(instruction);
+    }
+    Ok(ship.position.x.abs() + ship.position.y.abs())
+}
+
+pub fn solve_part2(instructions: &[NavInstruction]) -> Result<i32> {
+    let mut ship = Ship::new();
+    for instruction in instructions {
+        ship.apply_waypoint_instruction(instruction);
+    }
+    Ok(ship.position.x.abs() + ship.position.y.abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest::prelude::*;
+
+    fn action() -> impl Strategy<Value = Action> {
+        prop_oneof![
+            Just(Action::North),
+            Just(Action::South),
+            Just(Action::East),
+            Just(Action::West),
+            Just(Action::Forward),
+        ]
+    }
+
+    fn turn() -> impl Strategy<Value = Action> {
+        prop_oneof![Just(Action::Left), Just(Action::Right)]
+    }
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let instructions = Day12::parse_file(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            instructions,
+            vec![
+                NavInstruction {
+                    action: Action::Forward,
+                    value: 10,
+                },
+                NavInstruction {
+                    action: Action::North,
+                    value: 3,
+                },
+                NavInstruction {
+                    action: Action::Forward,
+                    value: 7,
+                },
+                NavInstruction {
+                    action: Action::Right,
+                    value: 90,
+                },
+                NavInstruction {
+                    action: Action::Forward,
+                    value: 11,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rotates_ship() {
+        let mut ship = Ship::new();
+        ship.apply_instruction(&NavInstruction {
+            action: Action::Right,
+            value: 270,
+        });
+        assert_eq!(ship.bearing, Bearing::North);
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_moves(action in action(), value in 0..=i32::MAX) {
+            let instruction = NavInstruction { action, value };
+            prop_assert_eq!(instruction.to_string().parse::<NavInstruction>().unwrap(), instruction);
+        }
+
+        #[test]
+        fn round_trips_turns(action in turn(), turns in 0..4i32) {
+            let instruction = NavInstruction { action, value: turns * 90 };
+            prop_assert_eq!(instruction.to_string().parse::<NavInstruction>().unwrap(), instruction);
+        }
+
+        #[test]
+        fn parses_any_line_without_panicking(line in "[^\\n]*|[NSEWLRFX][+-]?[0-9]{0,11}") {
+            if let Ok(instruction) = line.parse::<NavInstruction>() {
+                prop_assert!(instruction.value >= 0);
+                prop_assert_eq!(instruction.to_string().parse::<NavInstruction>().unwrap(), instruction);
+            }
+        }
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day12::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            25
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day12::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            286
+        );
+    }
+}
@@ -0,0 +1,150 @@
+//! Day 13: Shuttle Search.
+
+use anyhow::{anyhow, Result};
+use aoc_common::modular::{mod_inv, mod_mul};
+use aoc_common::{Answer, NoParams, ParseError, Solution, TokenError};
+use tracing::debug;
+
+// Shamelessly copied from: https://rosettacode.org/wiki/Chinese_remainder_theorem#Rust
+// I did not like this problem.
+
+/// The smallest non-negative number congruent to each residue modulo its pairwise coprime modulus.
+/// Fails if the product of the moduli doesn't fit in an `i64`.
+pub fn chinese_remainder(residues: &[i64], modulii: &[i64]) -> Result<i64> {
+    let prod = modulii
+        .iter()
+        .try_fold(1i64, |prod, &modulus| prod.checked_mul(modulus))
+        .ok_or_else(|| anyhow!("The product of the moduli {:?} overflows", modulii))?;
+
+    let mut sum = 0;
+
+    for (&residue, &modulus) in residues.iter().zip(modulii) {
+        let p = prod / modulus;
+        let inverse = mod_inv(p, modulus)
+            .ok_or_else(|| anyhow!("The moduli {:?} aren't pairwise coprime", modulii))?;
+        let term = mod_mul(mod_mul(residue, inverse, prod), p, prod);
+        sum = ((sum as i128 + term as i128) % prod as i128) as i64;
+    }
+
+    Ok(sum)
+}
+
+/// The earliest timestamp a bus can be caught at and the bus ids, with `None` for each `x`.
+#[derive(Debug, PartialEq)]
+pub struct Notes {
+    pub earliest: u32,
+    pub buses: Vec<Option<u32>>,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Notes;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Notes> {
+        let mut lines = input.lines();
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::unexpected_end(1, "the earliest timestamp"))?;
+        let earliest = line
+            .parse()
+            .map_err(|_| ParseError::invalid(1, 1, line, "expected a timestamp"))?;
+        let line = lines
+            .next()
+            .ok_or_else(|| ParseError::unexpected_end(2, "the bus ids"))?;
+        let buses = line
+            .split(',')
+            .map(|bus| match bus {
+                "x" => Ok(None),
+                _ => match bus.parse() {
+                    Ok(id) if id > 0 => Ok(Some(id)),
+                    _ => Err(TokenError::new(line, bus, "expected a bus id or x")),
+                },
+            })
+            .collect::<Result<Vec<Option<u32>>, TokenError>>()
+            .map_err(|error| ParseError::from_line(2, line, error.into()))?;
+        Ok(Notes { earliest, buses })
+    }
+
+    fn part1(notes: &Notes, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(notes)?.into())
+    }
+
+    fn part2(notes: &Notes, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(notes)?.into())
+    }
+}
+
+pub fn solve_part1(notes: &Notes) -> Result<u32> {
+    let earliest = notes.earliest;
+    debug!(earliest, "looking for the first bus to leave");
+    let (bus, min) = notes
+        .buses
+        .iter()
+        .filter_map(|&bus| bus)
+        .map(|bus| {
+            let mut time = bus;
+            while time < earliest {
+                time += bus;
+            }
+            (bus, time)
+        })
+        .min_by_key(|&(_, time)| time)
+        .ok_or_else(|| anyhow!("No buses are in service"))?;
+    Ok((min - earliest) * bus)
+}
+
+pub fn solve_part2(notes: &Notes) -> Result<i64> {
+    let buses: Vec<(i64, i64)> = notes
+        .buses
+        .iter()
+        .enumerate()
+        .filter_map(|(index, bus)| bus.map(|bus| (index as i64, bus as i64)))
+        .collect();
+    debug!(?buses, "lining up the buses at their offsets");
+
+    let modulii: Vec<i64> = buses.iter().map(|&(_, bus)| bus).collect();
+    let residues: Vec<i64> = buses.iter().map(|&(index, bus)| bus - index).collect();
+
+    chinese_remainder(residues.as_slice(), modulii.as_slice())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn finds_chinese_remainders() {
+        assert_eq!(chinese_remainder(&[2, 3, 2], &[3, 5, 7]).unwrap(), 23);
+        assert_eq!(
+            chinese_remainder(&[0, 0], &[4, 6]).unwrap_err().to_string(),
+            "The moduli [4, 6] aren't pairwise coprime"
+        );
+        assert_eq!(
+            chinese_remainder(&[1, 2, 3], &[2147483647, 2147483629, 2147483587])
+                .unwrap_err()
+                .to_string(),
+            "The product of the moduli [2147483647, 2147483629, 2147483587] overflows"
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day13::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            295
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day13::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            1068781
+        );
+    }
+}
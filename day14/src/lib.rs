@@ -0,0 +1,158 @@
+//! Day 14: Docking Data.
+
+use anyhow::{Error, Result};
+use aoc_common::{parse_lines, Answer, NoParams, Solution, TokenError};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    Mask(String),
+    Write { address: u64, value: u64 },
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (target, value) = s
+            .split_once(" = ")
+            .ok_or_else(|| TokenError::missing(s, "expected \" = \" and a value"))?;
+        if target == "mask" {
+            if value.len() != 36 || !value.chars().all(|c| matches!(c, '0' | '1' | 'X')) {
+                return Err(TokenError::new(s, value, "expected 36 bits of 0, 1 or X").into());
+            }
+            Ok(Instruction::Mask(value.to_owned()))
+        } else {
+            let address = target
+                .strip_prefix("mem[")
+                .and_then(|address| address.strip_suffix(']'))
+                .ok_or_else(|| TokenError::new(s, target, "expected mask or mem[address]"))?;
+            let address = address
+                .parse()
+                .map_err(|_| TokenError::new(s, address, "expected an address"))?;
+            let value = value
+                .parse()
+                .map_err(|_| TokenError::new(s, value, "expected a value"))?;
+            Ok(Instruction::Write { address, value })
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Vec<Instruction>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>> {
+        Ok(parse_lines(input, Instruction::from_str)?)
+    }
+
+    fn part1(program: &Vec<Instruction>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(program)?.into())
+    }
+
+    fn part2(program: &Vec<Instruction>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(program)?.into())
+    }
+}
+
+pub fn solve_part1(program: &[Instruction]) -> Result<u64> {
+    let mut or_mask = 0;
+    let mut and_mask = u64::MAX;
+    let mut memory = HashMap::new();
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(mask) => {
+                or_mask = u64::from_str_radix(&mask.replace("X", "0"), 2)?;
+                and_mask = u64::from_str_radix(&mask.replace("X", "1"), 2)?;
+            }
+            &Instruction::Write { address, value } => {
+                memory.insert(address, (value | or_mask) & and_mask);
+            }
+        }
+    }
+    Ok(memory.values().sum())
+}
+
+/// Applies a version 2 mask to an address, returning every address produced by the floating `X`
+/// bits. The mask must only contain `0`, `1` and `X`, which parsing checks.
+pub fn build_addresses(mask: &str, input_address: u64) -> Vec<u64> {
+    let mut addresses = Vec::new();
+    for (i, c) in mask.chars().rev().enumerate() {
+        let input = (input_address >> i) & 1;
+        match c {
+            '0' => {
+                if addresses.is_empty() {
+                    addresses.push(input);
+                } else {
+                    addresses = addresses.iter_mut().map(|v| (*v << 1) | input).collect();
+                }
+            }
+            '1' => {
+                if addresses.is_empty() {
+                    addresses.push(1);
+                } else {
+                    addresses = addresses.iter_mut().map(|v| (*v << 1) | 1).collect();
+                }
+            }
+            'X' => {
+                if addresses.is_empty() {
+                    addresses.push(0);
+                    addresses.push(1);
+                } else {
+                    let mut new_addresses = Vec::new();
+                    for v in addresses.iter() {
+                        new_addresses.push(*v << 1);
+                        new_addresses.push((*v << 1) | 1);
+                    }
+                    addresses = new_addresses;
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    addresses
+}
+
+pub fn solve_part2(program: &[Instruction]) -> Result<u64> {
+    let mut mask = "";
+    let mut memory = HashMap::new();
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(new_mask) => mask = new_mask,
+            &Instruction::Write { address, value } => {
+                for address in build_addresses(mask, address) {
+                    memory.insert(address, value);
+                }
+            }
+        }
+    }
+    Ok(memory.values().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day14::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            165
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day14::parse_file(TEST_INPUT2).unwrap()).unwrap(),
+            208
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Day 15: Rambunctious Recitation.
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse_lines, Answer, ParseError, Solution, TokenError};
+use serde::Deserialize;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+fn take_turn(nums: &mut Vec<usize>) {
+    if let Some(position) = nums[..nums.len() - 1]
+        .iter()
+        .rposition(|&num| num == nums[nums.len() - 1])
+    {
+        nums.push(nums.len() - (position + 1));
+    } else {
+        nums.push(0);
+    }
+}
+
+/// The memory game, which only remembers the last turn each number was spoken on.
+#[derive(Debug)]
+pub struct Game {
+    turn: usize,
+    last_num: usize,
+    prev_nums: HashMap<usize, usize>,
+}
+
+impl Game {
+    pub fn new(starting_nums: &[usize]) -> Result<Self> {
+        let (&last_num, prev_nums) = starting_nums
+            .split_last()
+            .ok_or_else(|| anyhow!("input is empty"))?;
+        let turn = starting_nums.len();
+        let prev_nums = prev_nums
+            .iter()
+            .enumerate()
+            .map(|(position, &num)| (num, position + 1))
+            .collect();
+        Ok(Self {
+            turn,
+            last_num,
+            prev_nums,
+        })
+    }
+
+    pub fn take_turn(&mut self) {
+        self.turn += 1;
+        self.last_num = match self.prev_nums.entry(self.last_num) {
+            Entry::Occupied(mut entry) => {
+                let prev_turn = entry.insert(self.turn - 1);
+                self.turn - prev_turn - 1
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(self.turn - 1);
+                0
+            }
+        };
+    }
+}
+
+/// Which turn's number each part asks for.
+pub const PART1_TURNS: usize = 2020;
+pub const PART2_TURNS: usize = 30000000;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_turns: usize,
+    pub part2_turns: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1_turns: PART1_TURNS,
+            part2_turns: PART2_TURNS,
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<usize>;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        let starting_nums = parse_lines(input, |line| {
+            line.split(',')
+                .map(|num| {
+                    let num = num.trim();
+                    num.parse()
+                        .map_err(|_| TokenError::new(line, num, "expected a number").into())
+                })
+                .collect::<Result<Vec<usize>>>()
+        })?
+        .concat();
+        if starting_nums.is_empty() {
+            return Err(ParseError::unexpected_end(1, "the starting numbers").into());
+        }
+        Ok(starting_nums)
+    }
+
+    fn part1(starting_nums: &Vec<usize>, params: &Params) -> Result<Answer> {
+        Ok(solve_part1(starting_nums, params.part1_turns)?.into())
+    }
+
+    fn part2(starting_nums: &Vec<usize>, params: &Params) -> Result<Answer> {
+        Ok(solve_part2(starting_nums, params.part2_turns)?.into())
+    }
+}
+
+/// Plays the game by looking back through every number spoken so far, which is only fast enough for
+/// a few thousand turns.
+pub fn solve_part1(starting_nums: &[usize], turns: usize) -> Result<usize> {
+    if starting_nums.is_empty() {
+        return Err(anyhow!("input is empty"));
+    }
+    if turns == 0 {
+        return Err(anyhow!("The game lasts at least one turn"));
+    }
+    let mut nums = starting_nums.to_vec();
+    while nums.len() < turns {
+        take_turn(&mut nums);
+    }
+    Ok(nums[turns - 1])
+}
+
+pub fn solve_part2(starting_nums: &[usize], turns: usize) -> Result<usize> {
+    if turns == 0 {
+        return Err(anyhow!("The game lasts at least one turn"));
+    }
+    if let Some(&num) = starting_nums.get(turns - 1) {
+        return Ok(num);
+    }
+    let mut game = Game::new(starting_nums)?;
+    for _ in game.turn..turns {
+        game.take_turn();
+    }
+    Ok(game.last_num)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day15::parse_file(TEST_INPUT).unwrap(), PART1_TURNS).unwrap(),
+            436
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day15::parse_file(TEST_INPUT).unwrap(), PART2_TURNS).unwrap(),
+            175594
+        );
+    }
+
+    #[test]
+    fn solves_any_number_of_turns() {
+        let starting_nums = Day15::parse_file(TEST_INPUT).unwrap();
+        assert_eq!(solve_part1(&starting_nums, 10).unwrap(), 0);
+        assert_eq!(solve_part2(&starting_nums, 10).unwrap(), 0);
+        assert_eq!(solve_part2(&starting_nums, 9).unwrap(), 4);
+        assert_eq!(solve_part1(&starting_nums, 2).unwrap(), 3);
+        assert_eq!(solve_part2(&starting_nums, 2).unwrap(), 3);
+        assert_eq!(solve_part2(&starting_nums, 3).unwrap(), 6);
+        assert!(solve_part1(&starting_nums, 0).is_err());
+        assert!(solve_part2(&starting_nums, 0).is_err());
+    }
+}
@@ -0,0 +1,356 @@
+//! Day 16: Ticket Translation.
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::records::parse_record_lines;
+use aoc_common::{Answer, NoParams, ParseError, Records, Solution, TokenError};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use tracing::debug;
+
+#[derive(Debug, PartialEq)]
+pub struct Rule {
+    pub field: String,
+    pub ranges: (RangeInclusive<usize>, RangeInclusive<usize>),
+}
+
+pub type Ticket = Vec<usize>;
+
+#[derive(Debug)]
+pub struct Notes {
+    pub rules: Vec<Rule>,
+    pub your_ticket: Ticket,
+    pub nearby_tickets: Vec<Ticket>,
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (field, ranges) = s
+            .split_once(": ")
+            .ok_or_else(|| TokenError::missing(s, "expected \": \" after the field name"))?;
+        let (range1, range2) = ranges
+            .split_once(" or ")
+            .ok_or_else(|| TokenError::new(s, ranges, "expected two ranges joined by \" or \""))?;
+        let parse_range = |range: &str| {
+            let (start, end) = range
+                .split_once('-')
+                .ok_or_else(|| TokenError::new(s, range, "expected a range like 1-3"))?;
+            let parse_bound = |bound: &str| {
+                bound
+                    .parse()
+                    .map_err(|_| TokenError::new(s, bound, "expected a number"))
+            };
+            Ok::<_, TokenError>(parse_bound(start)?..=parse_bound(end)?)
+        };
+        Ok(Self {
+            field: field.to_owned(),
+            ranges: (parse_range(range1)?, parse_range(range2)?),
+        })
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (range1, range2) = &self.ranges;
+        write!(
+            f,
+            "{}: {}-{} or {}-{}",
+            self.field,
+            range1.start(),
+            range1.end(),
+            range2.start(),
+            range2.end()
+        )
+    }
+}
+
+impl Rule {
+    pub fn validate_num(&self, num: usize) -> bool {
+        self.ranges.0.contains(&num) || self.ranges.1.contains(&num)
+    }
+}
+
+/// Checks that every number on the ticket is valid for at least one rule.
+pub fn validate_ticket(rules: &[Rule], ticket: &[usize]) -> bool {
+    ticket
+        .iter()
+        .all(|&num| rules.iter().any(|rule| rule.validate_num(num)))
+}
+
+fn parse_ticket(line: &str) -> Result<Ticket> {
+    line.split(',')
+        .map(|num| {
+            num.parse()
+                .map_err(|_| TokenError::new(line, num, "expected a number").into())
+        })
+        .collect()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Notes;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Notes> {
+        let mut records = Records::new(input);
+        let rules = records.expect("the rules")?.parse_lines(Rule::from_str)?;
+        let record = records.expect("\"your ticket:\"")?;
+        let your_ticket = match record.section("your ticket:")? {
+            [line] => line.parse(parse_ticket)?,
+            [] => {
+                let end = record.last_line() + 1;
+                return Err(ParseError::unexpected_end(end, "your ticket").into());
+            }
+            [_, extra, ..] => {
+                return Err(ParseError::invalid(
+                    extra.number,
+                    1,
+                    extra.text,
+                    "expected a blank line after your ticket",
+                )
+                .into())
+            }
+        };
+        let record = records.expect("\"nearby tickets:\"")?;
+        let nearby_tickets = parse_record_lines(record.section("nearby tickets:")?, parse_ticket)?;
+        records.finish()?;
+        Ok(Notes {
+            rules,
+            your_ticket,
+            nearby_tickets,
+        })
+    }
+
+    fn part1(notes: &Notes, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(notes)?.into())
+    }
+
+    fn part2(notes: &Notes, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(notes)?.into())
+    }
+}
+
+pub fn solve_part1(notes: &Notes) -> Result<usize> {
+    let rules = &notes.rules;
+    Ok(notes.nearby_tickets.iter().fold(0, |acc, ticket| {
+        if let Some(invalid_num) = ticket
+            .iter()
+            .find(|&num| !rules.iter().any(|rule| rule.validate_num(*num)))
+        {
+            acc + invalid_num
+        } else {
+            acc
+        }
+    }))
+}
+
+/// For each field, how many of the valid tickets (counting your own) have a number the field allows
+/// at each position.
+#[derive(Debug)]
+pub struct RuleValidations<'a> {
+    pub valid_tickets: usize,
+    pub counts: HashMap<&'a str, HashMap<usize, usize>>,
+}
+
+impl RuleValidations<'_> {
+    /// The positions that every valid ticket allows for `field`, in order.
+    pub fn possible_positions(&self, field: &str) -> Vec<usize> {
+        let mut positions: Vec<usize> = self
+            .counts
+            .get(field)
+            .into_iter()
+            .flatten()
+            .filter(|&(_, &count)| count == self.valid_tickets)
+            .map(|(&position, _)| position)
+            .collect();
+        positions.sort_unstable();
+        positions
+    }
+}
+
+/// Counts the positions each field's rule allows on the tickets, ignoring invalid tickets.
+pub fn rule_validations(notes: &Notes) -> RuleValidations<'_> {
+    let rules = &notes.rules;
+    let your_ticket = &notes.your_ticket;
+    let mut tickets = notes.nearby_tickets.clone();
+    tickets.push(your_ticket.clone());
+
+    // TODO: might be able to get rid of this extra iteration
+    let valid_tickets: Vec<Ticket> = tickets
+        .into_iter()
+        .filter(|ticket| validate_ticket(rules, ticket))
+        .collect();
+
+    let mut rule_validations: HashMap<&str, HashMap<usize, usize>> = HashMap::new();
+    for ticket in valid_tickets.iter() {
+        for (i, num) in ticket.iter().enumerate() {
+            for rule in rules.iter() {
+                if rule.validate_num(*num) {
+                    let rule_entry = rule_validations.entry(&rule.field).or_default();
+                    let validate_count = rule_entry.entry(i).or_insert(0);
+                    *validate_count += 1;
+                }
+            }
+        }
+    }
+
+    RuleValidations {
+        valid_tickets: valid_tickets.len(),
+        counts: rule_validations,
+    }
+}
+
+/// Works out which position on the tickets each field is at, ignoring invalid tickets. Fields that
+/// can't be narrowed down to a single position are left out.
+pub fn map_fields(notes: &Notes) -> HashMap<&str, usize> {
+    let rules = &notes.rules;
+    let rule_validations = rule_validations(notes);
+
+    let mut rule_positions: HashMap<&str, usize> = HashMap::new();
+    let mut assigned_positions = HashSet::new();
+    while rule_positions.len() != rules.len() {
+        let assigned_count = rule_positions.len();
+        for (field, validations) in rule_validations.counts.iter() {
+            if rule_positions.contains_key(field) {
+                continue;
+            }
+            let possible_positions: Vec<(&usize, &usize)> = validations
+                .iter()
+                .filter(|&(position, count)| {
+                    count == &rule_validations.valid_tickets
+                        && !assigned_positions.contains(&position)
+                })
+                .collect();
+            if possible_positions.len() == 1 {
+                let position = possible_positions.last().expect("count is 1").0;
+                rule_positions.insert(field, *position);
+                assigned_positions.insert(position);
+            }
+        }
+        if rule_positions.len() == assigned_count {
+            break;
+        }
+    }
+
+    rule_positions
+}
+
+pub fn solve_part2(notes: &Notes) -> Result<usize> {
+    let rule_positions = map_fields(notes);
+    debug!(?rule_positions, "mapped fields to positions");
+    [
+        "departure location",
+        "departure station",
+        "departure platform",
+        "departure track",
+        "departure date",
+        "departure time",
+    ]
+    .iter()
+    .map(|field| {
+        rule_positions
+            .get(field)
+            .and_then(|&position| notes.your_ticket.get(position))
+            .ok_or_else(|| anyhow!("Couldn't find the {} field on your ticket", field))
+    })
+    .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::ParseError;
+    use proptest::prelude::*;
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day16::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            71
+        );
+    }
+
+    #[test]
+    fn maps_fields() {
+        let notes = Day16::parse_file(TEST_INPUT2).unwrap();
+        let fields = map_fields(&notes);
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields["row"], 0);
+        assert_eq!(fields["class"], 1);
+        assert_eq!(fields["seat"], 2);
+    }
+
+    #[test]
+    fn counts_rule_validations() {
+        let notes = Day16::parse_file(TEST_INPUT2).unwrap();
+        let validations = rule_validations(&notes);
+        assert_eq!(validations.valid_tickets, 4);
+        assert_eq!(validations.possible_positions("row"), vec![0, 1, 2]);
+        assert_eq!(validations.possible_positions("class"), vec![1, 2]);
+        assert_eq!(validations.possible_positions("seat"), vec![2]);
+        assert!(validations.possible_positions("zone").is_empty());
+    }
+
+    #[test]
+    fn reports_malformed_notes() {
+        let error = |input: &str| {
+            Day16::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("class: 1-3 or 5-7\nrow: 6-11 or 33-x\n"),
+            ParseError::invalid(2, 17, "x", "expected a number")
+        );
+        assert_eq!(
+            error("class: 1-3 or 5-7\n\nyour ticket:\n7,1,14\n\nnearby tickets:\n7,3,47\n40,,4\n"),
+            ParseError::invalid(8, 4, "", "expected a number")
+        );
+        assert_eq!(
+            error("class: 1-3 or 5-7\n\n7,1,14\n"),
+            ParseError::invalid(3, 1, "7,1,14", "expected \"your ticket:\"")
+        );
+        assert_eq!(
+            error("class: 1-3 or 5-7\r\n\r\nyour ticket:\r\n7,1,14\r\n"),
+            ParseError::unexpected_end(5, "\"nearby tickets:\"")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_rules(
+            field in "[a-z][a-z ]{0,20}",
+            bounds: (usize, usize, usize, usize),
+        ) {
+            let rule = Rule {
+                field,
+                ranges: (bounds.0..=bounds.1, bounds.2..=bounds.3),
+            };
+            prop_assert_eq!(rule.to_string().parse::<Rule>().unwrap(), rule);
+        }
+
+        #[test]
+        fn parses_any_rule_without_panicking(
+            line in "[^\\n]*|[a-z ]{0,8}(: )?[0-9]{0,3}-?[0-9]{0,3}( or )?[0-9-]{0,7}"
+        ) {
+            if let Ok(rule) = line.parse::<Rule>() {
+                prop_assert_eq!(rule.to_string().parse::<Rule>().unwrap(), rule);
+            }
+        }
+    }
+
+    #[test]
+    fn fails_without_departure_fields() {
+        assert!(solve_part2(&Day16::parse_file(TEST_INPUT2).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,384 @@
+//! Day 17: Conway Cubes.
+
+#[macro_use]
+extern crate lazy_static;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{Answer, Grid2D, Solution};
+use serde::Deserialize;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::BufRead;
+use std::ops::Add;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Coordinate {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+    pub w: isize,
+}
+
+impl Add for Coordinate {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+            w: self.w + other.w,
+        }
+    }
+}
+
+/// The offsets to the 26 neighbors of a cube in three dimensions.
+pub fn adjacent_vectors3() -> [Coordinate; 26] {
+    let mut i = 0;
+    let mut vectors: [Coordinate; 26] = [Coordinate {
+        x: 0,
+        y: 0,
+        z: 0,
+        w: 0,
+    }; 26];
+    for z in -1..=1 {
+        for y in -1..=1 {
+            for x in -1..=1 {
+                if !(x == 0 && y == 0 && z == 0) {
+                    vectors[i].x = x;
+                    vectors[i].y = y;
+                    vectors[i].z = z;
+                    i += 1;
+                }
+            }
+        }
+    }
+    vectors
+}
+
+/// The offsets to the 80 neighbors of a hypercube in four dimensions.
+pub fn adjacent_vectors4() -> [Coordinate; 80] {
+    let mut i = 0;
+    let mut vectors: [Coordinate; 80] = [Coordinate {
+        x: 0,
+        y: 0,
+        z: 0,
+        w: 0,
+    }; 80];
+    for w in -1..=1 {
+        for z in -1..=1 {
+            for y in -1..=1 {
+                for x in -1..=1 {
+                    if !(x == 0 && y == 0 && z == 0 && w == 0) {
+                        vectors[i].x = x;
+                        vectors[i].y = y;
+                        vectors[i].z = z;
+                        vectors[i].w = w;
+                        i += 1;
+                    }
+                }
+            }
+        }
+    }
+    vectors
+}
+
+lazy_static! {
+    static ref ADJACENT_VECTORS3: [Coordinate; 26] = adjacent_vectors3();
+    static ref ADJACENT_VECTORS4: [Coordinate; 80] = adjacent_vectors4();
+}
+
+#[derive(Clone)]
+pub enum Cube {
+    Active,
+    Inactive,
+}
+
+impl TryFrom<char> for Cube {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '#' => Ok(Cube::Active),
+            '.' => Ok(Cube::Inactive),
+            _ => Err(anyhow!("Unrecognized cube character: {}", c)),
+        }
+    }
+}
+
+impl fmt::Display for Cube {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cube::Active => write!(f, "#"),
+            Cube::Inactive => write!(f, "."),
+        }
+    }
+}
+
+impl Cube {
+    pub fn is_active(&self) -> bool {
+        match self {
+            Cube::Active => true,
+            Cube::Inactive => false,
+        }
+    }
+}
+
+/// The cubes within the bounds as a dense grid of `x, y` layers, indexed by `w` and then `z`
+/// relative to the bounds' minimum.
+#[derive(Clone)]
+pub struct Grid {
+    layers: Vec<Vec<Grid2D<Cube>>>,
+    bounds: Bounds,
+}
+
+#[derive(Clone)]
+struct Bounds {
+    min: Coordinate,
+    max: Coordinate,
+}
+
+impl Grid {
+    pub fn from_reader<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        let layer = Grid2D::parse(&input, "'#' or '.'", |c| Cube::try_from(c).ok())?;
+        let bounds = Bounds {
+            min: Coordinate {
+                x: 0,
+                y: 0,
+                z: 0,
+                w: 0,
+            },
+            max: Coordinate {
+                x: layer.width() as isize - 1,
+                y: layer.height() as isize - 1,
+                z: 0,
+                w: 0,
+            },
+        };
+        Ok(Self {
+            layers: vec![vec![layer]],
+            bounds,
+        })
+    }
+
+    fn get(&self, coord: &Coordinate) -> Option<&Cube> {
+        let min = &self.bounds.min;
+        let w = usize::try_from(coord.w - min.w).ok()?;
+        let z = usize::try_from(coord.z - min.z).ok()?;
+        self.layers
+            .get(w)?
+            .get(z)?
+            .get_signed(coord.x - min.x, coord.y - min.y)
+    }
+
+    fn get_active_neighbors(&self, coord: &Coordinate, vectors: &[Coordinate]) -> usize {
+        vectors
+            .iter()
+            .filter(
+                |&&vector| matches!(self.get(&(*coord + vector)), Some(cube) if cube.is_active()),
+            )
+            .count()
+    }
+
+    fn new_cube_state(cube: Option<&Cube>, active_neighbors: usize) -> Cube {
+        match cube {
+            Some(Cube::Active) => {
+                if active_neighbors == 2 || active_neighbors == 3 {
+                    Cube::Active
+                } else {
+                    Cube::Inactive
+                }
+            }
+            _ => {
+                if active_neighbors == 3 {
+                    Cube::Active
+                } else {
+                    Cube::Inactive
+                }
+            }
+        }
+    }
+
+    /// Grows the bounds by `growth` in every direction and works out every cube's new state from
+    /// its neighbors at `vectors`.
+    fn run(&mut self, growth: Coordinate, vectors: &[Coordinate]) {
+        let min = Coordinate {
+            x: self.bounds.min.x - growth.x,
+            y: self.bounds.min.y - growth.y,
+            z: self.bounds.min.z - growth.z,
+            w: self.bounds.min.w - growth.w,
+        };
+        let max = self.bounds.max + growth;
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        let layers = (min.w..=max.w)
+            .map(|w| {
+                (min.z..=max.z)
+                    .map(|z| {
+                        Grid2D::from_fn(width, height, |(x, y)| {
+                            let coord = Coordinate {
+                                x: min.x + x as isize,
+                                y: min.y + y as isize,
+                                z,
+                                w,
+                            };
+                            let active_neighbors = self.get_active_neighbors(&coord, vectors);
+                            Grid::new_cube_state(self.get(&coord), active_neighbors)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        self.layers = layers;
+        self.bounds = Bounds { min, max };
+    }
+
+    /// Runs one cycle in three dimensions.
+    pub fn run_cycle(&mut self) {
+        let growth = Coordinate {
+            x: 1,
+            y: 1,
+            z: 1,
+            w: 0,
+        };
+        self.run(growth, &*ADJACENT_VECTORS3);
+    }
+
+    /// Runs one cycle in four dimensions.
+    pub fn run_hypercube_cycle(&mut self) {
+        let growth = Coordinate {
+            x: 1,
+            y: 1,
+            z: 1,
+            w: 1,
+        };
+        self.run(growth, &*ADJACENT_VECTORS4);
+    }
+
+    pub fn active_count(&self) -> usize {
+        self.layers
+            .iter()
+            .flatten()
+            .map(|layer| layer.iter().filter(|(_, cube)| cube.is_active()).count())
+            .sum()
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (w, layers) in (self.bounds.min.w..).zip(&self.layers) {
+            for (z, layer) in (self.bounds.min.z..).zip(layers) {
+                writeln!(f, "z={} w={}", z, w)?;
+                writeln!(f, "{}", layer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How many cycles the boot process runs for.
+pub const CYCLES: usize = 6;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub cycles: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { cycles: CYCLES }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Grid;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Grid> {
+        Grid::from_reader(input.as_bytes())
+    }
+
+    fn part1(grid: &Grid, params: &Params) -> Result<Answer> {
+        Ok(solve_part1(grid, params.cycles)?.into())
+    }
+
+    fn part2(grid: &Grid, params: &Params) -> Result<Answer> {
+        Ok(solve_part2(grid, params.cycles)?.into())
+    }
+}
+
+pub fn solve_part1(grid: &Grid, cycles: usize) -> Result<usize> {
+    let mut grid = grid.clone();
+
+    for _ in 0..cycles {
+        grid.run_cycle();
+    }
+
+    Ok(grid.active_count())
+}
+
+pub fn solve_part2(grid: &Grid, cycles: usize) -> Result<usize> {
+    let mut grid = grid.clone();
+
+    for _ in 0..cycles {
+        grid.run_hypercube_cycle();
+    }
+
+    Ok(grid.active_count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::open_input;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let reader = open_input(TEST_INPUT).unwrap();
+        let grid = Grid::from_reader(reader).unwrap();
+
+        assert_eq!(
+            grid.to_string(),
+            r#"
+z=0 w=0
+.#.
+..#
+###
+
+"#
+            .trim_start()
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day17::parse_file(TEST_INPUT).unwrap(), CYCLES).unwrap(),
+            112
+        );
+        assert_eq!(
+            solve_part1(&Day17::parse_file(TEST_INPUT).unwrap(), 1).unwrap(),
+            11
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day17::parse_file(TEST_INPUT).unwrap(), 1).unwrap(),
+            29
+        );
+        assert_eq!(
+            solve_part2(&Day17::parse_file(TEST_INPUT).unwrap(), CYCLES).unwrap(),
+            848
+        );
+    }
+}
@@ -0,0 +1,219 @@
+//! Day 18: Operation Order.
+
+extern crate nom;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{parse_lines, Answer, NoParams, Solution, TokenError};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::char,
+    character::complete::{digit1 as digit, space0 as space},
+    combinator::map_res,
+    multi::fold_many0,
+    sequence::{delimited, pair},
+    IResult,
+};
+use std::str::FromStr;
+
+// From: https://github.com/Geal/nom/blob/master/tests/arithmetic.rs
+fn flat_parens(i: &str) -> IResult<&str, i64> {
+    delimited(space, delimited(tag("("), flat_expr, tag(")")), space)(i)
+}
+
+fn flat_factor(i: &str) -> IResult<&str, i64> {
+    alt((
+        map_res(delimited(space, digit, space), FromStr::from_str),
+        flat_parens,
+    ))(i)
+}
+
+/// Evaluates an expression where `+` and `*` have the same precedence.
+pub fn flat_expr(i: &str) -> IResult<&str, i64> {
+    let (i, init) = flat_factor(i)?;
+
+    fold_many0(
+        pair(alt((char('+'), char('*'))), flat_factor),
+        init,
+        |acc, (op, val): (char, i64)| {
+            if op == '+' {
+                acc + val
+            } else if op == '*' {
+                acc * val
+            } else {
+                acc
+            }
+        },
+    )(i)
+}
+
+fn precedent_parens(i: &str) -> IResult<&str, i64> {
+    delimited(space, delimited(tag("("), precedent_expr, tag(")")), space)(i)
+}
+
+fn precedent_factor(i: &str) -> IResult<&str, i64> {
+    alt((
+        map_res(delimited(space, digit, space), FromStr::from_str),
+        precedent_parens,
+    ))(i)
+}
+
+fn sum(i: &str) -> IResult<&str, i64> {
+    let (i, init) = precedent_factor(i)?;
+
+    fold_many0(
+        pair(char('+'), precedent_factor),
+        init,
+        |acc, (op, val): (char, i64)| {
+            if op == '+' {
+                acc + val
+            } else {
+                acc
+            }
+        },
+    )(i)
+}
+
+/// Evaluates an expression where `+` has a higher precedence than `*`.
+pub fn precedent_expr(i: &str) -> IResult<&str, i64> {
+    let (i, init) = sum(i)?;
+
+    fold_many0(pair(char('*'), sum), init, |acc, (op, val): (char, i64)| {
+        if op == '*' {
+            acc * val
+        } else {
+            acc
+        }
+    })(i)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    /// The expressions are evaluated with different precedence rules in each part, so they're
+    /// kept as text until then.
+    type Input = Vec<String>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(parse_lines(input, |line| match flat_expr(line) {
+            Ok(("", _)) => Ok(line.to_owned()),
+            Ok((rest, _)) => Err(TokenError::new(line, rest, "expected + or *").into()),
+            Err(nom::Err::Error(error)) | Err(nom::Err::Failure(error)) => Err(TokenError::new(
+                line,
+                error.input,
+                "expected a number or parenthesized expression",
+            )
+            .into()),
+            Err(nom::Err::Incomplete(_)) => {
+                Err(TokenError::missing(line, "expected the rest of the expression").into())
+            }
+        })?)
+    }
+
+    fn part1(expressions: &Vec<String>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(expressions)?.into())
+    }
+
+    fn part2(expressions: &Vec<String>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(expressions)?.into())
+    }
+}
+
+fn evaluate(expression: &str, expr: fn(&str) -> IResult<&str, i64>) -> Result<i64> {
+    match expr(expression) {
+        Ok(("", value)) => Ok(value),
+        Ok((rest, _)) => Err(anyhow!("Failed to evaluate {:?} at {:?}", expression, rest)),
+        Err(error) => Err(anyhow!("Failed to evaluate {:?}: {}", expression, error)),
+    }
+}
+
+pub fn solve_part1(expressions: &[String]) -> Result<i64> {
+    expressions
+        .iter()
+        .map(|expression| evaluate(expression, flat_expr))
+        .sum()
+}
+
+pub fn solve_part2(expressions: &[String]) -> Result<i64> {
+    expressions
+        .iter()
+        .map(|expression| evaluate(expression, precedent_expr))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::ParseError;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(flat_expr("1 + 2 * 3 + 4 * 5 + 6"), Ok(("", 71)));
+        assert_eq!(flat_expr("1 + (2 * 3) + (4 * (5 + 6))"), Ok(("", 51)));
+        assert_eq!(flat_expr("2 * 3 + (4 * 5)"), Ok(("", 26)));
+        assert_eq!(flat_expr("5 + (8 * 3 + 9 + 3 * 4 * 3)"), Ok(("", 437)));
+        assert_eq!(
+            flat_expr("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            Ok(("", 12240))
+        );
+        assert_eq!(
+            flat_expr("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            Ok(("", 13632))
+        );
+        assert_eq!(
+            solve_part1(&Day18::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            26457
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(precedent_expr("1 + 2 * 3 + 4 * 5 + 6"), Ok(("", 231)));
+        assert_eq!(precedent_expr("1 + (2 * 3) + (4 * (5 + 6))"), Ok(("", 51)));
+        assert_eq!(precedent_expr("2 * 3 + (4 * 5)"), Ok(("", 46)));
+        assert_eq!(
+            precedent_expr("5 + (8 * 3 + 9 + 3 * 4 * 3)"),
+            Ok(("", 1445))
+        );
+        assert_eq!(
+            precedent_expr("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))"),
+            Ok(("", 669060))
+        );
+        assert_eq!(
+            precedent_expr("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2"),
+            Ok(("", 23340))
+        );
+        assert_eq!(
+            solve_part2(&Day18::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            694173
+        );
+    }
+
+    #[test]
+    fn reports_malformed_expressions() {
+        let error = |input: &str| {
+            Day18::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("1 + 2\n1 + (2 * 3"),
+            ParseError::invalid(2, 3, "+ (2 * 3", "expected + or *")
+        );
+        assert_eq!(
+            error("x + 2"),
+            ParseError::invalid(
+                1,
+                1,
+                "x + 2",
+                "expected a number or parenthesized expression"
+            )
+        );
+    }
+}
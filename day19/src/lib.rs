@@ -0,0 +1,394 @@
+//! Day 19: Monster Messages.
+
+use anyhow::{anyhow, Error, Result};
+use aoc_common::{Answer, NoParams, ParseError, Records, Solution, TokenError};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Rule {
+    Char(char),
+    Seq(Vec<usize>),
+    Or((Vec<usize>, Vec<usize>)),
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(parse_rule(s, s)?)
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let seq = |seq: &[usize]| {
+            seq.iter()
+                .map(|num| num.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+        match self {
+            Rule::Char(c) => write!(f, "\"{}\"", c),
+            Rule::Seq(nums) => write!(f, "{}", seq(nums)),
+            Rule::Or((left, right)) => write!(f, "{} | {}", seq(left), seq(right)),
+        }
+    }
+}
+
+/// Parses the rule `s`, which is part of `line`.
+fn parse_rule(line: &str, s: &str) -> Result<Rule, TokenError> {
+    let parse_seq = |seq: &str| {
+        seq.split(' ')
+            .map(|num| {
+                num.parse()
+                    .map_err(|_| TokenError::new(line, num, "expected a rule number"))
+            })
+            .collect::<Result<Vec<usize>, TokenError>>()
+    };
+    if let Some((left, right)) = s.split_once(" | ") {
+        Ok(Rule::Or((parse_seq(left)?, parse_seq(right)?)))
+    } else if s.starts_with('"') {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next(), chars.next(), chars.next()) {
+            (Some('"'), Some(c), Some('"'), None) => Ok(Rule::Char(c)),
+            _ => Err(TokenError::new(
+                line,
+                s,
+                "expected a single quoted character",
+            )),
+        }
+    } else {
+        Ok(Rule::Seq(parse_seq(s)?))
+    }
+}
+
+/// Parses a rule along with its index, like `1: 2 3 | 3 2`.
+pub fn parse_indexed_rule(s: &str) -> Result<(usize, Rule)> {
+    let (index, rule) = s
+        .split_once(": ")
+        .ok_or_else(|| TokenError::missing(s, "expected \": \" after the rule number"))?;
+    let index = index
+        .parse()
+        .map_err(|_| TokenError::new(s, index, "expected a rule number"))?;
+    Ok((index, parse_rule(s, rule)?))
+}
+
+/// Visits the rules that `number` can start with, tracking the rules on the current `path` so that
+/// a rule which can start with itself fails instead of recursing forever when matching.
+fn check_left_recursion(
+    rules: &HashMap<usize, Rule>,
+    number: usize,
+    path: &mut Vec<usize>,
+    checked: &mut HashSet<usize>,
+) -> Result<()> {
+    if let Some(start) = path.iter().position(|&rule| rule == number) {
+        let cycle = path[start..]
+            .iter()
+            .chain(Some(&number))
+            .map(|rule| rule.to_string())
+            .collect::<Vec<String>>();
+        return Err(anyhow!(
+            "Rule {} is left-recursive: {}",
+            number,
+            cycle.join(" -> ")
+        ));
+    }
+    if !checked.insert(number) {
+        return Ok(());
+    }
+    let first_rules = match rules.get(&number) {
+        Some(Rule::Seq(seq)) => seq.first().into_iter().collect(),
+        Some(Rule::Or((left, right))) => left.first().into_iter().chain(right.first()).collect(),
+        Some(Rule::Char(_)) | None => Vec::new(),
+    };
+    path.push(number);
+    for &first_rule in first_rules {
+        check_left_recursion(rules, first_rule, path, checked)?;
+    }
+    path.pop();
+    Ok(())
+}
+
+fn string_matches_rule<'a>(
+    s: &'a str,
+    rules: &HashMap<usize, Rule>,
+    rule: &Rule,
+    ends: bool,
+) -> Vec<(&'a str, bool)> {
+    match rule {
+        Rule::Char(c) => {
+            if let Some(rest) = s.strip_prefix(*c) {
+                if !ends || rest.is_empty() {
+                    vec![(rest, true)]
+                } else {
+                    vec![(rest, false)]
+                }
+            } else {
+                vec![(s, false)]
+            }
+        }
+        Rule::Seq(seq) => {
+            let mut possibilities: Vec<(&'a str, bool)> = Vec::new();
+            for (i, rule_index) in seq.iter().enumerate() {
+                let rule = match rules.get(rule_index) {
+                    Some(rule) => rule,
+                    None => return Vec::new(),
+                };
+                if i == 0 {
+                    possibilities = string_matches_rule(
+                        s,
+                        rules,
+                        rule,
+                        if i == seq.len() - 1 { ends } else { false },
+                    );
+                } else {
+                    let mut new_possibilities = Vec::new();
+                    for possibility in possibilities {
+                        if possibility.1 {
+                            new_possibilities.append(&mut string_matches_rule(
+                                possibility.0,
+                                rules,
+                                rule,
+                                if i == seq.len() - 1 { ends } else { false },
+                            ));
+                        }
+                    }
+                    possibilities = new_possibilities;
+                }
+            }
+            possibilities
+        }
+        Rule::Or((left, right)) => {
+            let mut possibilities = string_matches_rule(s, rules, &Rule::Seq(left.to_vec()), ends);
+            possibilities.append(&mut string_matches_rule(
+                s,
+                rules,
+                &Rule::Seq(right.to_vec()),
+                ends,
+            ));
+            possibilities
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Notes {
+    pub rules: HashMap<usize, Rule>,
+    pub messages: Vec<String>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    type Input = Notes;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Notes> {
+        let mut records = Records::new(input);
+        let rule_record = records.expect("the rules")?;
+        let mut rules: HashMap<usize, Rule> = HashMap::new();
+        for line in rule_record.lines() {
+            let (index, rule) = line.parse(parse_indexed_rule)?;
+            rules.insert(index, rule);
+        }
+        if !rules.contains_key(&0) {
+            return Err(ParseError::unexpected_end(rule_record.last_line() + 1, "rule 0").into());
+        }
+        for line in rule_record.lines() {
+            let missing_rule = line.text.split(' ').find(|&token| {
+                token
+                    .parse()
+                    .is_ok_and(|index: usize| !rules.contains_key(&index))
+            });
+            if let Some(token) = missing_rule {
+                let error = TokenError::new(line.text, token, "expected the number of a rule");
+                return Err(ParseError::from_line(line.number, line.text, error.into()).into());
+            }
+        }
+        let messages = records
+            .next()
+            .map(|record| {
+                record
+                    .lines()
+                    .iter()
+                    .map(|line| line.text.to_owned())
+                    .collect()
+            })
+            .unwrap_or_default();
+        records.finish()?;
+        Ok(Notes { rules, messages })
+    }
+
+    fn part1(notes: &Notes, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(notes)?.into())
+    }
+
+    fn part2(notes: &Notes, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(notes)?.into())
+    }
+}
+
+/// Counts the messages that completely match rule 0, failing if the rules are left-recursive.
+pub fn count_matches(rules: &HashMap<usize, Rule>, messages: &[String]) -> Result<usize> {
+    let rule = match rules.get(&0) {
+        Some(rule) => rule,
+        None => return Ok(0),
+    };
+    let mut numbers: Vec<usize> = rules.keys().copied().collect();
+    numbers.sort_unstable();
+    let mut checked = HashSet::new();
+    for number in numbers {
+        check_left_recursion(rules, number, &mut Vec::new(), &mut checked)?;
+    }
+    Ok(messages
+        .iter()
+        .filter(|s| {
+            let results = string_matches_rule(s, rules, rule, true);
+            results.iter().any(|result| result.1 && result.0.is_empty())
+        })
+        .count())
+}
+
+pub fn solve_part1(notes: &Notes) -> Result<usize> {
+    count_matches(&notes.rules, &notes.messages)
+}
+
+pub fn solve_part2(notes: &Notes) -> Result<usize> {
+    let mut rules = notes.rules.clone();
+    rules.insert(8, Rule::Or((vec![42], vec![42, 8])));
+    rules.insert(11, Rule::Or((vec![42, 31], vec![42, 11, 31])));
+    count_matches(&rules, &notes.messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::ParseError;
+
+    use aoc_common::read_input;
+    use maplit::hashmap;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    fn rule() -> impl Strategy<Value = Rule> {
+        let seq = || vec(any::<usize>(), 1..4);
+        prop_oneof![
+            any::<char>()
+                .prop_filter("rules are one line", |&c| c != '\n')
+                .prop_map(Rule::Char),
+            seq().prop_map(Rule::Seq),
+            (seq(), seq()).prop_map(Rule::Or),
+        ]
+    }
+
+    const TEST_INPUT1: &str = "input/test1.txt";
+    const TEST_INPUT2: &str = "input/test2.txt";
+    const TEST_INPUT3: &str = "input/test3.txt";
+
+    #[test]
+    fn parses_input() {
+        let rules: HashMap<usize, Rule> = read_input(TEST_INPUT1)
+            .unwrap()
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(|line| parse_indexed_rule(line).unwrap())
+            .collect();
+
+        assert_eq!(
+            rules,
+            hashmap! {
+                0 => Rule::Seq(vec![4, 1, 5]),
+                1 => Rule::Or((vec![2, 3], vec![3, 2])),
+                2 => Rule::Or((vec![4, 4], vec![5, 5])),
+                3 => Rule::Or((vec![4, 5], vec![5, 4])),
+                4 => Rule::Char('a'),
+                5 => Rule::Char('b'),
+            }
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_trips_rules(rule in rule()) {
+            prop_assert_eq!(rule.to_string().parse::<Rule>().unwrap(), rule);
+        }
+
+        #[test]
+        fn parses_any_rule_without_panicking(line in "[^\\n]*|[0-9]{0,3}(: )?(\"[^\\n]{0,2}\"?|[0-9 |]{0,8})") {
+            if let Ok(rule) = line.parse::<Rule>() {
+                prop_assert_eq!(rule.to_string().parse::<Rule>().unwrap(), rule);
+            }
+            if let Ok((index, rule)) = parse_indexed_rule(&line) {
+                let line = format!("{}: {}", index, rule);
+                prop_assert_eq!(parse_indexed_rule(&line).unwrap(), (index, rule));
+            }
+        }
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day19::parse_file(TEST_INPUT1).unwrap()).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part1(&Day19::parse_file(TEST_INPUT2).unwrap()).unwrap(),
+            3
+        );
+        assert_eq!(
+            solve_part1(&Day19::parse_file(TEST_INPUT3).unwrap()).unwrap(),
+            1
+        );
+        assert_eq!(
+            solve_part2(&Day19::parse_file(TEST_INPUT2).unwrap()).unwrap(),
+            12
+        );
+    }
+
+    #[test]
+    fn reports_malformed_rules() {
+        let error = |input: &str| {
+            Day19::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("0: 1 2\n1: \"a\"\n2: 1 | x\n\nab\n"),
+            ParseError::invalid(3, 8, "x", "expected a rule number")
+        );
+        assert_eq!(
+            error("0: 1 3\n1: \"a\"\n\nab\n"),
+            ParseError::invalid(1, 6, "3", "expected the number of a rule")
+        );
+        assert_eq!(
+            error("1: \"a\"\n\na\n"),
+            ParseError::unexpected_end(2, "rule 0")
+        );
+    }
+
+    #[test]
+    fn fails_on_left_recursion() {
+        let notes = Day19::parse("0: 1 2\n1: \"a\"\n2: 3 | 1\n3: 2 1\n\naa\n").unwrap();
+        assert_eq!(
+            solve_part1(&notes).unwrap_err().to_string(),
+            "Rule 2 is left-recursive: 2 -> 3 -> 2"
+        );
+        let notes =
+            Day19::parse("0: 8 11\n8: 31\n11: 42 31\n42: 1 | 8\n1: \"a\"\n31: \"b\"\n\nbab\n")
+                .unwrap();
+        assert_eq!(solve_part1(&notes).unwrap(), 1);
+        assert_eq!(
+            solve_part2(&notes).unwrap_err().to_string(),
+            "Rule 8 is left-recursive: 8 -> 42 -> 8"
+        );
+    }
+}
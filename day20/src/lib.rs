@@ -0,0 +1,476 @@
+//! Day 20: Jurassic Jigsaw.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use aoc_common::{Answer, Line, NoParams, ParseError, Record, Records, Solution, TokenError};
+use tracing::debug;
+
+/// A square grid of pixels, `true` where there is a `#`.
+pub type Pixels = Vec<Vec<bool>>;
+
+/// The sea monster's shape, which may appear in the image under any rotation or flip.
+pub const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// Rotates the pixels a quarter turn clockwise.
+pub fn rotate(pixels: &[Vec<bool>]) -> Pixels {
+    let size = pixels.len();
+    (0..size)
+        .map(|y| (0..size).map(|x| pixels[size - 1 - x][y]).collect())
+        .collect()
+}
+
+/// Flips the pixels horizontally.
+pub fn flip(pixels: &[Vec<bool>]) -> Pixels {
+    pixels
+        .iter()
+        .map(|row| row.iter().rev().copied().collect())
+        .collect()
+}
+
+/// All 8 ways the pixels can be rotated and flipped, starting with the pixels as they are.
+pub fn orientations(pixels: &[Vec<bool>]) -> Vec<Pixels> {
+    let mut orientations = Vec::with_capacity(8);
+    for mut orientation in [pixels.to_vec(), flip(pixels)] {
+        for _ in 0..4 {
+            let rotated = rotate(&orientation);
+            orientations.push(orientation);
+            orientation = rotated;
+        }
+    }
+    orientations
+}
+
+/// An edge read left to right or top to bottom, with the reversed edge under the same key, so that
+/// edges which could be matched by flipping a tile share a key.
+fn edge_key(edge: &[bool]) -> Vec<bool> {
+    let reversed: Vec<bool> = edge.iter().rev().copied().collect();
+    if reversed.as_slice() < edge {
+        reversed
+    } else {
+        edge.to_vec()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Tile {
+    pub id: u64,
+    pub pixels: Pixels,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Tile {}:", self.id)?;
+        for row in &self.pixels {
+            for &pixel in row {
+                write!(f, "{}", if pixel { '#' } else { '.' })?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Tile {
+    pub fn top(&self) -> Vec<bool> {
+        self.pixels[0].clone()
+    }
+
+    pub fn bottom(&self) -> Vec<bool> {
+        self.pixels[self.pixels.len() - 1].clone()
+    }
+
+    pub fn left(&self) -> Vec<bool> {
+        self.pixels.iter().map(|row| row[0]).collect()
+    }
+
+    pub fn right(&self) -> Vec<bool> {
+        self.pixels.iter().map(|row| row[row.len() - 1]).collect()
+    }
+
+    /// The top, right, bottom and left edges.
+    pub fn edges(&self) -> [Vec<bool>; 4] {
+        [self.top(), self.right(), self.bottom(), self.left()]
+    }
+
+    /// The tile under every rotation and flip.
+    pub fn orientations(&self) -> impl Iterator<Item = Tile> + '_ {
+        orientations(&self.pixels)
+            .into_iter()
+            .map(move |pixels| Tile {
+                id: self.id,
+                pixels,
+            })
+    }
+
+    /// The pixels without the border, which only exists to line the tiles up.
+    pub fn interior(&self) -> Pixels {
+        let size = self.pixels.len();
+        self.pixels[1..size - 1]
+            .iter()
+            .map(|row| row[1..size - 1].to_vec())
+            .collect()
+    }
+}
+
+/// Parses a tile from its record: a `Tile 1234:` header followed by the rows of pixels.
+fn parse_tile(record: &Record) -> Result<Tile, ParseError> {
+    let (header, rows) = record
+        .lines()
+        .split_first()
+        .expect("records are never empty");
+    let Line {
+        number,
+        text: header,
+    } = *header;
+    let id = header
+        .strip_prefix("Tile ")
+        .and_then(|header| header.strip_suffix(':'))
+        .ok_or_else(|| ParseError::invalid(number, 1, header, "expected \"Tile <id>:\""))?;
+    let id = id.parse().map_err(|_| {
+        ParseError::from_line(
+            number,
+            header,
+            TokenError::new(header, id, "expected a tile id").into(),
+        )
+    })?;
+    let mut pixels = Vec::new();
+    for &Line { number, text: line } in rows {
+        let row = line
+            .char_indices()
+            .map(|(index, c)| match c {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(ParseError::from_line(
+                    number,
+                    line,
+                    TokenError::char_at(line, index, "expected '#' or '.'").into(),
+                )),
+            })
+            .collect::<Result<Vec<bool>, ParseError>>()?;
+        if row.len() != pixels.first().map_or(row.len(), Vec::len) {
+            return Err(ParseError::invalid(
+                number,
+                1,
+                line,
+                "expected every row of the tile to be the same width",
+            ));
+        }
+        pixels.push(row);
+    }
+    if pixels.len() < 3 || pixels.len() != pixels[0].len() {
+        return Err(ParseError::unexpected_end(
+            record.last_line() + 1,
+            format!("tile {} to be square and at least 3 pixels wide", id),
+        ));
+    }
+    Ok(Tile { id, pixels })
+}
+
+/// For each edge, the tiles that have it in some orientation.
+fn tiles_by_edge(tiles: &[Tile]) -> HashMap<Vec<bool>, Vec<u64>> {
+    let mut tiles_by_edge: HashMap<Vec<bool>, Vec<u64>> = HashMap::new();
+    for tile in tiles {
+        for edge in tile.edges().iter() {
+            tiles_by_edge
+                .entry(edge_key(edge))
+                .or_default()
+                .push(tile.id);
+        }
+    }
+    tiles_by_edge
+}
+
+/// The tiles with only two edges matching another tile's edge, which must be the corners.
+pub fn find_corners(tiles: &[Tile]) -> Vec<&Tile> {
+    let tiles_by_edge = tiles_by_edge(tiles);
+    tiles
+        .iter()
+        .filter(|tile| {
+            tile.edges()
+                .iter()
+                .filter(|edge| tiles_by_edge[&edge_key(edge)].len() > 1)
+                .count()
+                == 2
+        })
+        .collect()
+}
+
+/// Lays the tiles out in a square with every pair of neighboring edges matching, starting from a
+/// corner in the top left.
+pub fn assemble(tiles: &[Tile]) -> Result<Vec<Vec<Tile>>> {
+    let size = (1..=tiles.len())
+        .find(|size| size * size >= tiles.len())
+        .filter(|size| size * size == tiles.len())
+        .ok_or_else(|| anyhow!("{} tiles can't make a square image", tiles.len()))?;
+    let tiles_by_edge = tiles_by_edge(tiles);
+    let tiles_by_id: HashMap<u64, &Tile> = tiles.iter().map(|tile| (tile.id, tile)).collect();
+    let is_outer_edge = |edge: &[bool]| tiles_by_edge[&edge_key(edge)].len() == 1;
+
+    let corner = find_corners(tiles)
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow!("No corner tiles found"))?;
+    let corner = corner
+        .orientations()
+        .find(|tile| is_outer_edge(&tile.top()) && is_outer_edge(&tile.left()))
+        .ok_or_else(|| anyhow!("Tile {} can't be turned into a corner", corner.id))?;
+
+    let mut image: Vec<Vec<Tile>> = vec![vec![corner]];
+    for y in 0..size {
+        for x in 0..size {
+            if x == 0 && y == 0 {
+                continue;
+            }
+            let (neighbor, edge) = if x == 0 {
+                let above = &image[y - 1][0];
+                (above, above.bottom())
+            } else {
+                let left = &image[y][x - 1];
+                (left, left.right())
+            };
+            let next_id = tiles_by_edge[&edge_key(&edge)]
+                .iter()
+                .find(|&&id| id != neighbor.id)
+                .ok_or_else(|| anyhow!("No tile fits at row {}, column {}", y + 1, x + 1))?;
+            let tile = tiles_by_id[next_id]
+                .orientations()
+                .find(|tile| {
+                    let fits_left = x == 0 || tile.left() == image[y][x - 1].right();
+                    let fits_above = y == 0 || tile.top() == image[y - 1][x].bottom();
+                    fits_left && fits_above
+                })
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Tile {} doesn't fit at row {}, column {}",
+                        next_id,
+                        y + 1,
+                        x + 1
+                    )
+                })?;
+            if x == 0 {
+                image.push(vec![tile]);
+            } else {
+                image[y].push(tile);
+            }
+        }
+    }
+    Ok(image)
+}
+
+/// Joins the interiors of the assembled tiles into one image.
+pub fn stitch(tiles: &[Vec<Tile>]) -> Pixels {
+    let mut image = Vec::new();
+    for row in tiles {
+        let interiors: Vec<Pixels> = row.iter().map(Tile::interior).collect();
+        for y in 0..interiors[0].len() {
+            image.push(
+                interiors
+                    .iter()
+                    .flat_map(|interior| interior[y].iter().copied())
+                    .collect(),
+            );
+        }
+    }
+    image
+}
+
+/// Marks every pixel that is part of a sea monster in the image as it is oriented.
+pub fn find_sea_monsters(image: &[Vec<bool>]) -> Vec<Vec<bool>> {
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| (y, x))
+        })
+        .collect();
+    let height = SEA_MONSTER.len();
+    let width = SEA_MONSTER[0].len();
+    let mut monster_pixels = vec![vec![false; image.len()]; image.len()];
+    for y in 0..(image.len() + 1).saturating_sub(height) {
+        for x in 0..(image.len() + 1).saturating_sub(width) {
+            if monster.iter().all(|&(dy, dx)| image[y + dy][x + dx]) {
+                for &(dy, dx) in &monster {
+                    monster_pixels[y + dy][x + dx] = true;
+                }
+            }
+        }
+    }
+    monster_pixels
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    type Input = Vec<Tile>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<Tile>> {
+        let mut records = Records::new(input);
+        let mut tiles = Vec::new();
+        for record in records.by_ref() {
+            let tile = parse_tile(&record)?;
+            if let Some(first) = tiles.first() {
+                let first: &Tile = first;
+                if tile.pixels.len() != first.pixels.len() {
+                    let header = &record.lines()[0];
+                    return Err(ParseError::invalid(
+                        header.number,
+                        1,
+                        header.text,
+                        format!("expected the tile to be the same size as tile {}", first.id),
+                    )
+                    .into());
+                }
+            }
+            tiles.push(tile);
+        }
+        if tiles.is_empty() {
+            return Err(ParseError::unexpected_end(records.end(), "a tile").into());
+        }
+        Ok(tiles)
+    }
+
+    fn part1(tiles: &Vec<Tile>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(tiles)?.into())
+    }
+
+    fn part2(tiles: &Vec<Tile>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(tiles)?.into())
+    }
+}
+
+pub fn solve_part1(tiles: &[Tile]) -> Result<u64> {
+    let corners = find_corners(tiles);
+    if corners.len() != 4 {
+        return Err(anyhow!("Expected 4 corner tiles, found {}", corners.len()));
+    }
+    Ok(corners.iter().map(|tile| tile.id).product())
+}
+
+pub fn solve_part2(tiles: &[Tile]) -> Result<usize> {
+    let assembled = assemble(tiles)?;
+    debug!(
+        ids = ?assembled
+            .iter()
+            .map(|row| row.iter().map(|tile| tile.id).collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        "assembled the tiles"
+    );
+    let image = stitch(&assembled);
+    for (orientation, image) in orientations(&image).into_iter().enumerate() {
+        let monster_pixels = find_sea_monsters(&image);
+        let monster_count: usize = monster_pixels
+            .iter()
+            .map(|row| row.iter().filter(|&&pixel| pixel).count())
+            .sum();
+        if monster_count > 0 {
+            debug!(orientation, monster_count, "found sea monsters");
+            let pixel_count: usize = image
+                .iter()
+                .map(|row| row.iter().filter(|&&pixel| pixel).count())
+                .sum();
+            return Ok(pixel_count - monster_count);
+        }
+    }
+    Err(anyhow!(
+        "No sea monsters found in any orientation of the image"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::read_input;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let tiles = Day20::parse_file(TEST_INPUT).unwrap();
+        assert_eq!(tiles.len(), 9);
+        assert_eq!(
+            tiles[0].to_string(),
+            r#"
+Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+"#
+            .trim_start()
+        );
+    }
+
+    #[test]
+    fn skips_extra_blank_lines() {
+        let input = read_input(TEST_INPUT).unwrap();
+        let spaced = format!("\n{}\n\n", input.replace("\n\n", "\n\n  \n\n"));
+        assert_eq!(
+            Day20::parse(&spaced).unwrap(),
+            Day20::parse(&input).unwrap()
+        );
+    }
+
+    #[test]
+    fn orients_pixels() {
+        let pixels = vec![vec![true, false], vec![false, false]];
+        assert_eq!(rotate(&pixels), vec![vec![false, true], vec![false, false]]);
+        assert_eq!(flip(&pixels), vec![vec![false, true], vec![false, false]]);
+        let orientations = orientations(&pixels);
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], pixels);
+        assert_eq!(orientations[4], flip(&pixels));
+    }
+
+    #[test]
+    fn assembles_image() {
+        let tiles = Day20::parse_file(TEST_INPUT).unwrap();
+        let image = assemble(&tiles).unwrap();
+        for y in 0..3 {
+            for x in 0..3 {
+                if x > 0 {
+                    assert_eq!(image[y][x].left(), image[y][x - 1].right());
+                }
+                if y > 0 {
+                    assert_eq!(image[y][x].top(), image[y - 1][x].bottom());
+                }
+            }
+        }
+        let image = stitch(&image);
+        assert_eq!(image.len(), 24);
+        assert!(image.iter().all(|row| row.len() == 24));
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day20::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            20899048083289
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day20::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            273
+        );
+    }
+}
@@ -0,0 +1,168 @@
+//! Day 21: Allergen Assessment.
+
+#[macro_use]
+extern crate lazy_static;
+
+use anyhow::Result;
+use aoc_common::{Answer, NoParams, ParseError, Solution};
+use regex::Regex;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use tracing::debug;
+
+lazy_static! {
+    static ref INGREDIENT_LIST: Regex =
+        Regex::new(r"(?P<ingredients>(?:\w+ )+)\(contains (?P<allergens>(:?\w+(:?, )?)+)\)")
+            .unwrap();
+}
+
+pub type IngredientCounts = HashMap<String, u32>;
+pub type AllergenIngredients = HashMap<String, HashSet<String>>;
+
+/// How many foods each ingredient appears in and, for each allergen, the ingredients that could
+/// contain it.
+#[derive(Debug)]
+pub struct Foods {
+    pub ingredient_counts: IngredientCounts,
+    pub allergen_ingredients: AllergenIngredients,
+}
+
+/// Counts how many foods each ingredient appears in and narrows down the ingredients that could
+/// contain each allergen to those in every food listing it.
+pub fn map_from_reader<R: BufRead>(reader: R) -> Result<(IngredientCounts, AllergenIngredients)> {
+    let mut all_ingredients = HashMap::new();
+    let mut allergen_ingredient_counts = HashMap::new();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let caps = INGREDIENT_LIST.captures(&line).ok_or_else(|| {
+            ParseError::invalid(
+                index + 1,
+                1,
+                &line,
+                "expected ingredients followed by (contains allergens)",
+            )
+        })?;
+        let ingredients: HashSet<String> = caps["ingredients"]
+            .split(' ')
+            .filter_map(|s| {
+                if s.is_empty() {
+                    None
+                } else {
+                    Some(s.to_owned())
+                }
+            })
+            .collect();
+        let allergens: HashSet<String> = caps["allergens"]
+            .split(", ")
+            .filter_map(|s| {
+                if s.is_empty() {
+                    None
+                } else {
+                    Some(s.to_owned())
+                }
+            })
+            .collect();
+        for allergen in allergens.iter() {
+            match allergen_ingredient_counts.entry(allergen.clone()) {
+                Entry::Occupied(entry) => {
+                    let entry: &mut HashSet<String> = entry.into_mut();
+                    *entry = entry.intersection(&ingredients).cloned().collect();
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(ingredients.iter().cloned().collect());
+                }
+            }
+        }
+        for ingredient in ingredients.into_iter() {
+            let entry = all_ingredients.entry(ingredient).or_insert(0);
+            *entry += 1;
+        }
+    }
+    Ok((all_ingredients, allergen_ingredient_counts))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    type Input = Foods;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Foods> {
+        let (ingredient_counts, allergen_ingredients) = map_from_reader(input.as_bytes())?;
+        Ok(Foods {
+            ingredient_counts,
+            allergen_ingredients,
+        })
+    }
+
+    fn part1(foods: &Foods, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(foods)?.into())
+    }
+
+    fn part2(foods: &Foods, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(foods)?.into())
+    }
+}
+
+pub fn solve_part1(foods: &Foods) -> Result<u32> {
+    let mut all_ingredients = foods.ingredient_counts.clone();
+    let mut allergen_ingredient_counts = foods.allergen_ingredients.clone();
+    while let Some(ingredient) = allergen_ingredient_counts
+        .values_mut()
+        .find(|ingredients| ingredients.len() == 1)
+        .and_then(|ingredients| ingredients.drain().next())
+    {
+        all_ingredients.remove(&ingredient);
+        for ingredients in allergen_ingredient_counts.values_mut() {
+            ingredients.remove(&ingredient);
+        }
+    }
+    Ok(all_ingredients.values().sum())
+}
+
+pub fn solve_part2(foods: &Foods) -> Result<String> {
+    let mut dangerous_ingredients = Vec::new();
+    let mut allergen_ingredient_counts = foods.allergen_ingredients.clone();
+    while let Some((allergen, ingredient)) = allergen_ingredient_counts
+        .iter_mut()
+        .find(|(_, ingredients)| ingredients.len() == 1)
+        .and_then(|(allergen, ingredients)| Some((allergen.clone(), ingredients.drain().next()?)))
+    {
+        for ingredients in allergen_ingredient_counts.values_mut() {
+            ingredients.remove(&ingredient);
+        }
+        dangerous_ingredients.push((allergen, ingredient));
+    }
+    dangerous_ingredients.sort_unstable_by_key(|(allergen, _)| allergen.clone());
+    debug!(?dangerous_ingredients, "matched allergens to ingredients");
+    Ok(dangerous_ingredients
+        .iter()
+        .map(|(_, ingredient)| ingredient.as_str())
+        .collect::<Vec<&str>>()
+        .join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day21::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day21::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            "mxmxvkd,sqjhc,fvjkl".to_string()
+        );
+    }
+}
@@ -0,0 +1,415 @@
+//! Day 22: Crab Combat.
+
+use std::collections::{HashSet, VecDeque};
+use std::fmt;
+use std::iter::FromIterator;
+
+use anyhow::{anyhow, Result};
+use aoc_common::records::parse_record_lines;
+use aoc_common::{Answer, NoParams, ParseError, Records, Solution};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Player {
+    One,
+    Two,
+}
+
+impl Player {
+    fn index(self) -> usize {
+        match self {
+            Player::One => 0,
+            Player::Two => 1,
+        }
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.index() + 1)
+    }
+}
+
+/// A player's cards, with the top of the deck at the front.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Deck {
+    cards: VecDeque<usize>,
+}
+
+impl FromIterator<usize> for Deck {
+    fn from_iter<I: IntoIterator<Item = usize>>(cards: I) -> Self {
+        Deck {
+            cards: cards.into_iter().collect(),
+        }
+    }
+}
+
+impl fmt::Display for Deck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards: Vec<String> = self.cards.iter().map(|card| card.to_string()).collect();
+        write!(f, "{}", cards.join(", "))
+    }
+}
+
+impl Deck {
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    pub fn draw(&mut self) -> Option<usize> {
+        self.cards.pop_front()
+    }
+
+    /// Puts the winning card and then the losing card on the bottom of the deck.
+    pub fn win_cards(&mut self, winning_card: usize, losing_card: usize) {
+        self.cards.push_back(winning_card);
+        self.cards.push_back(losing_card);
+    }
+
+    /// A copy of the top `count` cards, if the deck has that many.
+    pub fn sub_deck(&self, count: usize) -> Option<Deck> {
+        if count > self.len() {
+            return None;
+        }
+        Some(self.cards.iter().take(count).copied().collect())
+    }
+
+    /// The sum of each card multiplied by its position counting up from the bottom of the deck.
+    pub fn score(&self) -> usize {
+        self.cards
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(index, card)| (index + 1) * card)
+            .sum()
+    }
+}
+
+/// A game of Combat, or Recursive Combat where the winner of a round can be decided by a sub-game,
+/// optionally recording a trace of every round in the same format as the puzzle description.
+#[derive(Debug, Clone)]
+pub struct Game {
+    decks: [Deck; 2],
+    recursive: bool,
+    games: usize,
+    trace: Option<String>,
+}
+
+impl Game {
+    pub fn new(decks: &[Deck; 2], recursive: bool) -> Self {
+        Game {
+            decks: decks.clone(),
+            recursive,
+            games: 0,
+            trace: None,
+        }
+    }
+
+    pub fn with_trace(mut self) -> Self {
+        self.trace = Some(String::new());
+        self
+    }
+
+    pub fn decks(&self) -> &[Deck; 2] {
+        &self.decks
+    }
+
+    pub fn trace(&self) -> Option<&str> {
+        self.trace.as_deref()
+    }
+
+    fn log(&mut self, args: fmt::Arguments) {
+        if let Some(trace) = &mut self.trace {
+            trace.push_str(&args.to_string());
+        }
+    }
+
+    /// Plays the game to the end, leaving each player's final deck in `decks`, and returns the
+    /// winner.
+    pub fn play(&mut self) -> Result<Player> {
+        let (winner, decks) = self.play_game(self.decks.clone())?;
+        self.log(format_args!(
+            "\n== Post-game results ==\nPlayer 1's deck: {}\nPlayer 2's deck: {}\n",
+            decks[0], decks[1]
+        ));
+        self.decks = decks;
+        Ok(winner)
+    }
+
+    fn play_game(&mut self, mut decks: [Deck; 2]) -> Result<(Player, [Deck; 2])> {
+        self.games += 1;
+        let game = self.games;
+        if self.recursive {
+            self.log(format_args!("=== Game {} ===\n\n", game));
+        }
+        let mut seen = HashSet::new();
+        let mut round = 0;
+        let winner = loop {
+            if decks[1].is_empty() {
+                break Player::One;
+            }
+            if decks[0].is_empty() {
+                break Player::Two;
+            }
+            if !seen.insert(decks.clone()) {
+                if self.recursive {
+                    self.log(format_args!(
+                        "These decks were already played in game {}, so player 1 wins!\n\n",
+                        game
+                    ));
+                    break Player::One;
+                }
+                return Err(anyhow!("The game repeats itself after {} rounds", round));
+            }
+            round += 1;
+
+            if self.recursive {
+                self.log(format_args!("-- Round {} (Game {}) --\n", round, game));
+            } else {
+                self.log(format_args!("-- Round {} --\n", round));
+            }
+            self.log(format_args!(
+                "Player 1's deck: {}\nPlayer 2's deck: {}\n",
+                decks[0], decks[1]
+            ));
+            let cards = [
+                decks[0].draw().expect("deck is not empty"),
+                decks[1].draw().expect("deck is not empty"),
+            ];
+            self.log(format_args!(
+                "Player 1 plays: {}\nPlayer 2 plays: {}\n",
+                cards[0], cards[1]
+            ));
+
+            let sub_decks = if self.recursive {
+                decks[0].sub_deck(cards[0]).zip(decks[1].sub_deck(cards[1]))
+            } else {
+                None
+            };
+            let round_winner = match sub_decks {
+                Some((sub_deck1, sub_deck2)) => {
+                    self.log(format_args!(
+                        "Playing a sub-game to determine the winner...\n\n"
+                    ));
+                    let (sub_winner, _) = self.play_game([sub_deck1, sub_deck2])?;
+                    self.log(format_args!("...anyway, back to game {}.\n", game));
+                    sub_winner
+                }
+                None if cards[0] > cards[1] => Player::One,
+                None => Player::Two,
+            };
+            let (winning_card, losing_card) = match round_winner {
+                Player::One => (cards[0], cards[1]),
+                Player::Two => (cards[1], cards[0]),
+            };
+            decks[round_winner.index()].win_cards(winning_card, losing_card);
+
+            if self.recursive {
+                self.log(format_args!(
+                    "Player {} wins round {} of game {}!\n\n",
+                    round_winner, round, game
+                ));
+            } else {
+                self.log(format_args!("Player {} wins the round!\n\n", round_winner));
+            }
+        };
+        if self.recursive {
+            self.log(format_args!(
+                "The winner of game {} is player {}!\n\n",
+                game, winner
+            ));
+        }
+        Ok((winner, decks))
+    }
+}
+
+/// Parses `player`'s deck from the next record in `records`, which must start with its
+/// `Player N:` header followed by at least one card.
+fn parse_deck(records: &mut Records, player: Player) -> Result<Deck, ParseError> {
+    let expected = format!("Player {}:", player);
+    let record = records.expect(&format!("{:?}", expected))?;
+    let cards = record.section(&expected)?;
+    if cards.is_empty() {
+        return Err(ParseError::unexpected_end(
+            record.last_line() + 1,
+            format!("a card in player {}'s deck", player),
+        ));
+    }
+    let cards = parse_record_lines(cards, |line| {
+        line.parse().map_err(|_| anyhow!("expected a card number"))
+    })?;
+    Ok(cards.into_iter().collect())
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    type Input = [Deck; 2];
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<[Deck; 2]> {
+        let mut records = Records::new(input);
+        let decks = [
+            parse_deck(&mut records, Player::One)?,
+            parse_deck(&mut records, Player::Two)?,
+        ];
+        records.finish()?;
+        Ok(decks)
+    }
+
+    fn part1(decks: &[Deck; 2], _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(decks)?.into())
+    }
+
+    fn part2(decks: &[Deck; 2], _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(decks)?.into())
+    }
+}
+
+fn winning_score(mut game: Game) -> Result<usize> {
+    let winner = game.play()?;
+    Ok(game.decks()[winner.index()].score())
+}
+
+pub fn solve_part1(decks: &[Deck; 2]) -> Result<usize> {
+    winning_score(Game::new(decks, false))
+}
+
+pub fn solve_part2(decks: &[Deck; 2]) -> Result<usize> {
+    winning_score(Game::new(decks, true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let decks = Day22::parse_file(TEST_INPUT).unwrap();
+        assert_eq!(decks[0].to_string(), "9, 2, 6, 3, 1");
+        assert_eq!(decks[1].to_string(), "5, 8, 4, 7, 10");
+    }
+
+    #[test]
+    fn reports_malformed_decks() {
+        let error = |input: &str| {
+            Day22::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("Player 1:\n9\n\nPlayer 3:\n5\n"),
+            ParseError::invalid(4, 1, "Player 3:", "expected \"Player 2:\"")
+        );
+        assert_eq!(
+            error("Player 1:\nnine\n"),
+            ParseError::invalid(2, 1, "nine", "expected a card number")
+        );
+        assert_eq!(
+            error("Player 1:\n\nPlayer 2:\n5\n"),
+            ParseError::unexpected_end(2, "a card in player 1's deck")
+        );
+        assert_eq!(
+            error("Player 1:\n9\n"),
+            ParseError::unexpected_end(3, "\"Player 2:\"")
+        );
+        assert_eq!(
+            error("Player 1:\n9\n\nPlayer 2:\n5\n\nPlayer 3:\n"),
+            ParseError::invalid(7, 1, "Player 3:", "expected the end of the input")
+        );
+    }
+
+    #[test]
+    fn skips_extra_blank_lines() {
+        let decks = Day22::parse("\nPlayer 1:\n9\n2\n\n\n  \nPlayer 2:\n5\n\n\n").unwrap();
+        assert_eq!(
+            decks,
+            Day22::parse("Player 1:\n9\n2\n\nPlayer 2:\n5\n").unwrap()
+        );
+        assert_eq!(decks[0].to_string(), "9, 2");
+    }
+
+    #[test]
+    fn traces_games() {
+        let decks = Day22::parse_file(TEST_INPUT).unwrap();
+        let mut game = Game::new(&decks, false).with_trace();
+        assert_eq!(game.play().unwrap(), Player::Two);
+        let trace = game.trace().unwrap();
+        assert!(trace.starts_with(
+            "-- Round 1 --
+Player 1's deck: 9, 2, 6, 3, 1
+Player 2's deck: 5, 8, 4, 7, 10
+Player 1 plays: 9
+Player 2 plays: 5
+Player 1 wins the round!
+
+-- Round 2 --
+Player 1's deck: 2, 6, 3, 1, 9, 5
+Player 2's deck: 8, 4, 7, 10
+"
+        ));
+        assert!(trace.ends_with(
+            "Player 2 wins the round!
+
+
+== Post-game results ==
+Player 1's deck: \nPlayer 2's deck: 3, 2, 10, 6, 8, 5, 9, 4, 7, 1
+"
+        ));
+        assert_eq!(trace.matches("-- Round").count(), 29);
+
+        let mut game = Game::new(&decks, true).with_trace();
+        assert_eq!(game.play().unwrap(), Player::Two);
+        let trace = game.trace().unwrap();
+        assert!(trace.starts_with("=== Game 1 ===\n\n-- Round 1 (Game 1) --\n"));
+        assert!(trace.contains(
+            "Player 1 plays: 4
+Player 2 plays: 3
+Playing a sub-game to determine the winner...
+
+=== Game 2 ===
+"
+        ));
+        assert!(trace.ends_with(
+            "The winner of game 1 is player 2!
+
+
+== Post-game results ==
+Player 1's deck: \nPlayer 2's deck: 7, 5, 6, 2, 4, 1, 10, 8, 9, 3
+"
+        ));
+    }
+
+    #[test]
+    fn stops_repeated_games() {
+        let decks = [
+            vec![43, 19].into_iter().collect(),
+            vec![2, 29, 14].into_iter().collect(),
+        ];
+        assert_eq!(Game::new(&decks, true).play().unwrap(), Player::One);
+        assert!(Game::new(&decks, false).play().is_err());
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day22::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            306
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day22::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            291
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use anyhow::Result;
+use aoc_common::{input_from_args, run};
+use day22::Day22;
+
+const INPUT: &str = "input/input.txt";
+
+fn main() -> Result<()> {
+    let input = input_from_args(INPUT)?;
+    run::<Day22>(&input);
+    Ok(())
+}
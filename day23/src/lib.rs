@@ -0,0 +1,237 @@
+//! Day 23: Crab Cups.
+
+use anyhow::{Error, Result};
+use aoc_common::{Answer, ParseError, Solution, TokenError};
+use serde::Deserialize;
+use std::fmt;
+use std::str::FromStr;
+
+/// The cups are kept as a linked list stored in a `Vec` indexed by cup label, where
+/// `next_cups[label]` is the label of the cup immediately clockwise of it. Index 0 is unused since
+/// labels start at 1. This keeps every move constant time, which part 2 needs.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Circle {
+    next_cups: Vec<usize>,
+    current_cup: usize,
+}
+
+impl FromStr for Circle {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let cups = s
+            .char_indices()
+            .map(|(index, c)| match c.to_digit(10) {
+                Some(label) if label > 0 => Ok(label as usize),
+                _ => Err(TokenError::char_at(
+                    s,
+                    index,
+                    "expected a cup label from 1 to 9",
+                )),
+            })
+            .collect::<Result<Vec<usize>, TokenError>>()?;
+        if cups.len() < 5 {
+            return Err(TokenError::missing(s, "expected at least 5 cups").into());
+        }
+        let mut seen = vec![false; cups.len() + 1];
+        for (index, &cup) in cups.iter().enumerate() {
+            if cup > cups.len() || seen[cup] {
+                let reason = format!("expected each label from 1 to {} once", cups.len());
+                return Err(TokenError::char_at(s, index, reason).into());
+            }
+            seen[cup] = true;
+        }
+        let mut next_cups = vec![0; cups.len() + 1];
+        for (index, &cup) in cups.iter().enumerate() {
+            next_cups[cup] = cups[(index + 1) % cups.len()];
+        }
+        Ok(Circle {
+            next_cups,
+            current_cup: cups[0],
+        })
+    }
+}
+
+impl fmt::Display for Circle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, " ({}) ", self.current_cup)?;
+        for cup in self.cups_after(self.current_cup) {
+            write!(f, " {} ", cup)?;
+        }
+        writeln!(f)?;
+        Ok(())
+    }
+}
+
+impl Circle {
+    fn max_label(&self) -> usize {
+        self.next_cups.len() - 1
+    }
+
+    /// Iterates over every cup clockwise of `label`, stopping before coming back around to it.
+    pub fn cups_after(&self, label: usize) -> impl Iterator<Item = usize> + '_ {
+        let mut cup = label;
+        std::iter::from_fn(move || {
+            cup = self.next_cups[cup];
+            if cup == label {
+                None
+            } else {
+                Some(cup)
+            }
+        })
+    }
+
+    /// Moves the three cups after the current cup to after the destination cup and advances the
+    /// current cup.
+    pub fn run_move(&mut self) {
+        let first = self.next_cups[self.current_cup];
+        let second = self.next_cups[first];
+        let third = self.next_cups[second];
+        self.next_cups[self.current_cup] = self.next_cups[third];
+
+        let mut destination_cup = self.current_cup;
+        loop {
+            destination_cup = if destination_cup == 1 {
+                self.max_label()
+            } else {
+                destination_cup - 1
+            };
+            if destination_cup != first && destination_cup != second && destination_cup != third {
+                break;
+            }
+        }
+
+        self.next_cups[third] = self.next_cups[destination_cup];
+        self.next_cups[destination_cup] = first;
+        self.current_cup = self.next_cups[self.current_cup];
+    }
+
+    /// Adds cups labeled from one more than the highest label up to one million, continuing
+    /// clockwise from the last cup.
+    pub fn expand_for_part2(&mut self) {
+        let last_cup = self
+            .next_cups
+            .iter()
+            .position(|&next_cup| next_cup == self.current_cup)
+            .expect("circle is closed");
+        let first_new_cup = self.next_cups.len();
+        self.next_cups[last_cup] = first_new_cup;
+        self.next_cups.extend((first_new_cup + 1)..=1000000);
+        self.next_cups.push(self.current_cup);
+    }
+}
+
+/// How many moves the crab makes in each part.
+pub const PART1_MOVES: usize = 100;
+pub const PART2_MOVES: usize = 10000000;
+
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_moves: usize,
+    pub part2_moves: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self {
+            part1_moves: PART1_MOVES,
+            part2_moves: PART2_MOVES,
+        }
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    type Input = Circle;
+    type Params = Params;
+
+    fn parse(input: &str) -> Result<Circle> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::unexpected_end(1, "the cup labels"))?;
+        Ok(Circle::from_str(line).map_err(|error| ParseError::from_line(1, line, error))?)
+    }
+
+    fn part1(circle: &Circle, params: &Params) -> Result<Answer> {
+        Ok(solve_part1(circle, params.part1_moves)?.into())
+    }
+
+    fn part2(circle: &Circle, params: &Params) -> Result<Answer> {
+        Ok(solve_part2(circle, params.part2_moves)?.into())
+    }
+}
+
+pub fn solve_part1(circle: &Circle, n: usize) -> Result<u64> {
+    let mut circle = circle.clone();
+    for _ in 0..n {
+        circle.run_move();
+    }
+    Ok(circle
+        .cups_after(1)
+        .map(|cup| cup.to_string())
+        .collect::<Vec<String>>()
+        .join("")
+        .parse()?)
+}
+
+pub fn solve_part2(circle: &Circle, n: usize) -> Result<u64> {
+    let mut circle = circle.clone();
+    circle.expand_for_part2();
+    for _ in 0..n {
+        circle.run_move();
+    }
+    Ok(circle.cups_after(1).take(2).map(|cup| cup as u64).product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn reports_malformed_labels() {
+        let error = |input: &str| {
+            Day23::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("3890"),
+            ParseError::invalid(1, 4, "0", "expected a cup label from 1 to 9")
+        );
+        assert_eq!(
+            error("38925"),
+            ParseError::invalid(1, 2, "8", "expected each label from 1 to 5 once")
+        );
+        assert_eq!(
+            error("312"),
+            ParseError::invalid(1, 4, "", "expected at least 5 cups")
+        );
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day23::parse_file(TEST_INPUT).unwrap(), 10).unwrap(),
+            92658374
+        );
+        assert_eq!(
+            solve_part1(&Day23::parse_file(TEST_INPUT).unwrap(), 100).unwrap(),
+            67384529
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day23::parse_file(TEST_INPUT).unwrap(), 10000000).unwrap(),
+            149245887792
+        );
+    }
+}
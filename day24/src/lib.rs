@@ -0,0 +1,304 @@
+//! Day 24: Lobby Layout.
+
+use anyhow::Result;
+use aoc_common::{parse_lines, Answer, NoParams, Solution, TokenError};
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::Add;
+
+/// A hex tile's position in axial coordinates, where `q` increases to the east and `r` to the
+/// south east.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Default)]
+pub struct Coordinate {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl Add for Coordinate {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self {
+            q: self.q + other.q,
+            r: self.r + other.r,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::East => write!(f, "e"),
+            Direction::SouthEast => write!(f, "se"),
+            Direction::SouthWest => write!(f, "sw"),
+            Direction::West => write!(f, "w"),
+            Direction::NorthWest => write!(f, "nw"),
+            Direction::NorthEast => write!(f, "ne"),
+        }
+    }
+}
+
+impl Direction {
+    pub fn vector(self) -> Coordinate {
+        let (q, r) = match self {
+            Direction::East => (1, 0),
+            Direction::SouthEast => (0, 1),
+            Direction::SouthWest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (0, -1),
+            Direction::NorthEast => (1, -1),
+        };
+        Coordinate { q, r }
+    }
+}
+
+/// The offsets to the 6 neighbors of a hex tile.
+pub const ADJACENT_VECTORS: [Coordinate; 6] = [
+    Coordinate { q: 1, r: 0 },
+    Coordinate { q: 0, r: 1 },
+    Coordinate { q: -1, r: 1 },
+    Coordinate { q: -1, r: 0 },
+    Coordinate { q: 0, r: -1 },
+    Coordinate { q: 1, r: -1 },
+];
+
+/// Parses a line of directions with no delimiters between them, like `esenee`.
+pub fn parse_directions(line: &str) -> Result<Vec<Direction>, TokenError> {
+    let mut directions = Vec::new();
+    let mut chars = line.char_indices();
+    while let Some((index, c)) = chars.next() {
+        let direction = match c {
+            'e' => Direction::East,
+            'w' => Direction::West,
+            'n' | 's' => match (c, chars.next()) {
+                ('n', Some((_, 'e'))) => Direction::NorthEast,
+                ('n', Some((_, 'w'))) => Direction::NorthWest,
+                ('s', Some((_, 'e'))) => Direction::SouthEast,
+                ('s', Some((_, 'w'))) => Direction::SouthWest,
+                (_, Some((index, _))) => {
+                    return Err(TokenError::char_at(line, index, "expected 'e' or 'w'"))
+                }
+                (_, None) => return Err(TokenError::missing(line, "expected 'e' or 'w'")),
+            },
+            _ => {
+                return Err(TokenError::char_at(
+                    line,
+                    index,
+                    "expected a direction of e, se, sw, w, nw or ne",
+                ))
+            }
+        };
+        directions.push(direction);
+    }
+    Ok(directions)
+}
+
+/// The tile reached by following `directions` from the reference tile.
+pub fn follow(directions: &[Direction]) -> Coordinate {
+    directions
+        .iter()
+        .fold(Coordinate::default(), |coord, direction| {
+            coord + direction.vector()
+        })
+}
+
+#[derive(Clone)]
+pub struct Floor {
+    black_tiles: HashSet<Coordinate>,
+    bounds: Bounds,
+}
+
+#[derive(Clone, Default)]
+struct Bounds {
+    min: Coordinate,
+    max: Coordinate,
+}
+
+impl Floor {
+    /// Flips the tile at the end of each line of directions, starting from all white tiles.
+    pub fn from_directions(lines: &[Vec<Direction>]) -> Self {
+        let mut floor = Floor {
+            black_tiles: HashSet::new(),
+            bounds: Bounds::default(),
+        };
+        for directions in lines {
+            floor.flip(follow(directions));
+        }
+        floor
+    }
+
+    pub fn flip(&mut self, coord: Coordinate) {
+        if !self.black_tiles.remove(&coord) {
+            self.black_tiles.insert(coord);
+            self.bounds.min.q = self.bounds.min.q.min(coord.q);
+            self.bounds.min.r = self.bounds.min.r.min(coord.r);
+            self.bounds.max.q = self.bounds.max.q.max(coord.q);
+            self.bounds.max.r = self.bounds.max.r.max(coord.r);
+        }
+    }
+
+    pub fn black_tile_count(&self) -> usize {
+        self.black_tiles.len()
+    }
+
+    fn increase_bounds(&mut self) {
+        self.bounds.min.q -= 1;
+        self.bounds.min.r -= 1;
+        self.bounds.max.q += 1;
+        self.bounds.max.r += 1;
+    }
+
+    fn get_black_neighbors(&self, coord: &Coordinate) -> usize {
+        ADJACENT_VECTORS
+            .iter()
+            .filter(|&&vector| self.black_tiles.contains(&(*coord + vector)))
+            .count()
+    }
+
+    fn new_tile_state(is_black: bool, black_neighbors: usize) -> bool {
+        if is_black {
+            black_neighbors == 1 || black_neighbors == 2
+        } else {
+            black_neighbors == 2
+        }
+    }
+
+    /// Flips every tile for one day according to how many of its neighbors are black.
+    pub fn run_day(&mut self) {
+        self.increase_bounds();
+        let mut new_black_tiles = HashSet::new();
+        for r in self.bounds.min.r..=self.bounds.max.r {
+            for q in self.bounds.min.q..=self.bounds.max.q {
+                let coord = Coordinate { q, r };
+                let is_black = self.black_tiles.contains(&coord);
+                let black_neighbors = self.get_black_neighbors(&coord);
+                if Floor::new_tile_state(is_black, black_neighbors) {
+                    new_black_tiles.insert(coord);
+                }
+            }
+        }
+        self.black_tiles = new_black_tiles;
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    type Input = Vec<Vec<Direction>>;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Direction>>> {
+        Ok(parse_lines(input, |line| Ok(parse_directions(line)?))?)
+    }
+
+    fn part1(lines: &Vec<Vec<Direction>>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(lines)?.into())
+    }
+
+    fn part2(lines: &Vec<Vec<Direction>>, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part2(lines)?.into())
+    }
+}
+
+pub fn solve_part1(lines: &[Vec<Direction>]) -> Result<usize> {
+    Ok(Floor::from_directions(lines).black_tile_count())
+}
+
+pub fn solve_part2(lines: &[Vec<Direction>]) -> Result<usize> {
+    let mut floor = Floor::from_directions(lines);
+
+    for _ in 0..100 {
+        floor.run_day();
+    }
+
+    Ok(floor.black_tile_count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_common::ParseError;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        let lines = Day24::parse_file(TEST_INPUT).unwrap();
+        assert_eq!(lines.len(), 20);
+        assert_eq!(
+            lines[2]
+                .iter()
+                .map(|direction| direction.to_string())
+                .collect::<String>(),
+            "seswneswswsenwwnwse"
+        );
+        assert_eq!(
+            follow(&parse_directions("nwwswee").unwrap()),
+            Coordinate { q: 0, r: 0 }
+        );
+        assert_eq!(
+            follow(&parse_directions("esew").unwrap()),
+            Coordinate { q: 0, r: 1 }
+        );
+    }
+
+    #[test]
+    fn reports_malformed_directions() {
+        let error = |input: &str| {
+            Day24::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("e\nesnw"),
+            ParseError::invalid(2, 3, "n", "expected 'e' or 'w'")
+        );
+        assert_eq!(
+            error("sen"),
+            ParseError::invalid(1, 4, "", "expected 'e' or 'w'")
+        );
+        assert_eq!(
+            error("ex"),
+            ParseError::invalid(1, 2, "x", "expected a direction of e, se, sw, w, nw or ne")
+        );
+    }
+
+    #[test]
+    fn runs_days() {
+        let mut floor = Floor::from_directions(&Day24::parse_file(TEST_INPUT).unwrap());
+        let mut counts = vec![];
+        for _ in 0..10 {
+            floor.run_day();
+            counts.push(floor.black_tile_count());
+        }
+        assert_eq!(counts, vec![15, 12, 25, 14, 23, 28, 41, 37, 49, 37]);
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day24::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            10
+        );
+    }
+
+    #[test]
+    fn solves_part2() {
+        assert_eq!(
+            solve_part2(&Day24::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            2208
+        );
+    }
+}
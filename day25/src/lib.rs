@@ -0,0 +1,144 @@
+//! Day 25: Combo Breaker.
+
+use anyhow::{anyhow, Result};
+use aoc_common::modular::{mod_inv, mod_mul, mod_pow};
+use aoc_common::{parse_lines, Answer, NoParams, ParseError, Solution};
+use std::collections::HashMap;
+
+/// The subject number both public keys are made by transforming.
+pub const SUBJECT_NUMBER: i64 = 7;
+/// Every transformation is done modulo this prime.
+pub const MODULUS: i64 = 20201227;
+
+/// The card's and the door's public keys.
+#[derive(Debug, PartialEq)]
+pub struct PublicKeys {
+    pub card: i64,
+    pub door: i64,
+}
+
+/// Transforms `subject` by multiplying it by itself `loop_size` times.
+pub fn transform(subject: i64, loop_size: u64) -> i64 {
+    mod_pow(subject, loop_size, MODULUS)
+}
+
+/// Finds the smallest loop size that transforms `subject` into `public_key`: the discrete logarithm
+/// of the key, found by the baby-step giant-step algorithm in about `sqrt(MODULUS)` steps instead
+/// of looping until the key comes up.
+pub fn find_loop_size(subject: i64, public_key: i64) -> Option<u64> {
+    let steps = (MODULUS as f64).sqrt().ceil() as u64;
+    let mut baby_steps = HashMap::new();
+    let mut value = 1;
+    for j in 0..steps {
+        baby_steps.entry(value).or_insert(j);
+        value = mod_mul(value, subject, MODULUS);
+    }
+
+    let giant_step = mod_inv(transform(subject, steps), MODULUS)?;
+    let mut value = public_key;
+    for i in 0..steps {
+        if let Some(j) = baby_steps.get(&value) {
+            return Some(i * steps + j);
+        }
+        value = mod_mul(value, giant_step, MODULUS);
+    }
+    None
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    type Input = PublicKeys;
+    type Params = NoParams;
+
+    fn parse(input: &str) -> Result<PublicKeys> {
+        let keys = parse_lines(input, |line| match line.parse() {
+            Ok(key) if (1..MODULUS).contains(&key) => Ok(key),
+            _ => Err(anyhow!("expected a public key from 1 to {}", MODULUS - 1)),
+        })?;
+        match keys[..] {
+            [card, door] => Ok(PublicKeys { card, door }),
+            [_] => Err(ParseError::unexpected_end(2, "the door's public key").into()),
+            [] => Err(ParseError::unexpected_end(1, "the card's public key").into()),
+            _ => {
+                let line = input.lines().nth(2).unwrap_or_default();
+                Err(ParseError::invalid(3, 1, line, "expected only two public keys").into())
+            }
+        }
+    }
+
+    fn part1(keys: &PublicKeys, _params: &NoParams) -> Result<Answer> {
+        Ok(solve_part1(keys)?.into())
+    }
+
+    /// There's no second puzzle on the last day, just a star for finishing the rest, so there's
+    /// no answer to give or submit.
+    fn part2(_keys: &PublicKeys, _params: &NoParams) -> Result<Answer> {
+        Err(anyhow!("Day 25 has no part 2"))
+    }
+}
+
+pub fn solve_part1(keys: &PublicKeys) -> Result<i64> {
+    let card_loop_size = find_loop_size(SUBJECT_NUMBER, keys.card)
+        .ok_or_else(|| anyhow!("No loop size makes the card's public key {}", keys.card))?;
+    Ok(transform(keys.door, card_loop_size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "input/test.txt";
+
+    #[test]
+    fn parses_input() {
+        assert_eq!(
+            Day25::parse_file(TEST_INPUT).unwrap(),
+            PublicKeys {
+                card: 5764801,
+                door: 17807724
+            }
+        );
+        let error = |input: &str| {
+            Day25::parse(input)
+                .unwrap_err()
+                .downcast::<ParseError>()
+                .unwrap()
+        };
+        assert_eq!(
+            error("5764801\n0\n"),
+            ParseError::invalid(2, 1, "0", "expected a public key from 1 to 20201226")
+        );
+        assert_eq!(
+            error("5764801\n"),
+            ParseError::unexpected_end(2, "the door's public key")
+        );
+    }
+
+    #[test]
+    fn finds_loop_sizes() {
+        assert_eq!(find_loop_size(SUBJECT_NUMBER, 5764801), Some(8));
+        assert_eq!(find_loop_size(SUBJECT_NUMBER, 17807724), Some(11));
+        assert_eq!(transform(5764801, 11), 14897079);
+    }
+
+    #[test]
+    fn solves_part1() {
+        assert_eq!(
+            solve_part1(&Day25::parse_file(TEST_INPUT).unwrap()).unwrap(),
+            14897079
+        );
+    }
+
+    #[test]
+    fn has_no_part2() {
+        let keys = Day25::parse_file(TEST_INPUT).unwrap();
+        assert_eq!(
+            Day25::part2(&keys, &NoParams::default())
+                .unwrap_err()
+                .to_string(),
+            "Day 25 has no part 2"
+        );
+    }
+}